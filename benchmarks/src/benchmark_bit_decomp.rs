@@ -1,7 +1,5 @@
 use halo2_proofs::dev::MockProver;
-use halo2_proofs::halo2curves::bn256;
 use halo2_proofs::halo2curves::bn256::Fr;
-use num::{BigInt, Num};
 use std::time::Instant;
 
 use korrekt_V2;
@@ -67,12 +65,6 @@ pub fn run_underconstrained_benchmark_for_specified_size<const BITS: usize>() {
 
         let mut analyzer = analyzer::Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -81,14 +73,8 @@ pub fn run_underconstrained_benchmark_for_specified_size<const BITS: usize>() {
             },
         };
 
-    let modulus = bn256::fr::MODULUS_STR;
-    let without_prefix = modulus.trim_start_matches("0x");
-    let prime = BigInt::from_str_radix(without_prefix, 16)
-        .unwrap()
-        .to_string();
-
     let start = Instant::now();
-    let _result = analyzer.analyze_underconstrained(analyzer_input, &prime);
+    let _result = analyzer.analyze_underconstrained(analyzer_input);
     let duration = start.elapsed();
 
     println!(
@@ -1,6 +1,4 @@
-use halo2curves::bn256;
 use halo2_proofs::halo2curves::bn256::Fr;
-use num::{BigInt, Num};
 use std::marker::PhantomData;
 use std::time::{Duration, Instant};
 
@@ -81,12 +79,6 @@ pub fn run_underconstrained_benchmark_for_specified_size<const ROWS: usize>() ->
 
         let mut analyzer = analyzer::Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -97,7 +89,7 @@ pub fn run_underconstrained_benchmark_for_specified_size<const ROWS: usize>() ->
 
     let start = Instant::now();
     let output_status = analyzer
-    .analyze_underconstrained(analyzer_input, &prime)
+    .analyze_underconstrained(analyzer_input)
     .unwrap()
     .output_status;
     let duration = start.elapsed();
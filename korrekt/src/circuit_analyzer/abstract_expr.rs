@@ -1,5 +1,5 @@
 use super::{analyzable::AnalyzableField, halo2_proofs_libs::*};
-use anyhow::{anyhow,Context, Result};
+use anyhow::{Context, Result};
 use std::collections::HashSet;
 
 // abstract interpretation of expressions
@@ -84,11 +84,11 @@ pub fn eval_abstract<F: AnalyzableField>(
             let col = fixed_query.column_index;
             let row = (fixed_query.rotation.0 + row_num) as usize + region_begin;
 
-            let mut t = 0;
+            let mut is_zero = true;
             if let CellValue::Assigned(fixed_val) = fixed[col][row] {
-                t  = u64::from_str_radix(format!("{:?}",fixed_val).strip_prefix("0x").unwrap(), 16).unwrap();
+                is_zero = fixed_val.is_zero().into();
             }
-            if t == 0 {
+            if is_zero {
                 Ok(AbsResult::Zero)
             } else {
                 Ok(AbsResult::Variable)
@@ -139,6 +139,7 @@ pub fn eval_abstract<F: AnalyzableField>(
             }
         }
         #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs"))]
-        Expression::Challenge(_) => Err(anyhow!("Challenge expression in abstract evaluation resulted in Invalid Expression")),
+        // A challenge is a random field element, drawn by the verifier.
+        Expression::Challenge(_) => Ok(AbsResult::Variable),
     }
 }
\ No newline at end of file
@@ -1,5 +1,6 @@
 #[cfg(feature = "use_pse_v1_halo2_proofs")]
 use halo2curves::Group;
+use num::{BigUint, Num};
 use std::{
     collections::{HashMap, HashSet},
     ops::Range,
@@ -20,6 +21,25 @@ impl<F: Field + Group> AnalyzableField for F {}
 #[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
 impl<F: Field> AnalyzableField for F {}
 
+/// Converts a field element to its canonical integer representative in `[0, p)`.
+///
+/// All supported fields format their elements as a big-endian hex string (`0x...`),
+/// so this keeps the full width of the element instead of truncating it to a machine word.
+pub fn field_to_biguint<F: AnalyzableField>(value: &F) -> BigUint {
+    let repr = format!("{:?}", value);
+    BigUint::from_str_radix(repr.trim_start_matches("0x"), 16)
+        .expect("field elements are formatted as hex strings")
+}
+
+/// Returns the modulus of the field `F`, i.e. the canonical representative of `-1` plus one.
+pub fn field_modulus<F: AnalyzableField>() -> BigUint {
+    #[cfg(feature = "use_pse_v1_halo2_proofs")]
+    let minus_one = -F::one();
+    #[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
+    let minus_one = -F::ONE;
+    field_to_biguint(&minus_one) + 1u32
+}
+
 #[derive(Debug)]
 pub struct Analyzable<F: AnalyzableField> {
     pub k: u32,
@@ -47,6 +67,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
         NR: Into<String>,
         N: FnOnce() -> NR,
     {
+        if !self.records_layout() {
+            return;
+        }
 
         assert!(self.current_region.is_none());
 
@@ -83,6 +106,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
     }
 
     fn exit_region(&mut self) {
+        if !self.records_layout() {
+            return;
+        }
         self.regions.push(self.current_region.take().unwrap());
     }
 
@@ -91,6 +117,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
         A: FnOnce() -> AR,
         AR: Into<String>,
     {
+        if !self.records_layout() {
+            return Ok(());
+        }
         if !self.usable_rows.contains(&row) {
             return Err(Error::not_enough_rows_available(self.k));
         }
@@ -182,6 +211,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
         A: FnOnce() -> AR,
         AR: Into<String>,
     {
+        if !self.records_layout() {
+            return Ok(());
+        }
         if !self.usable_rows.contains(&row) {
             return Err(Error::not_enough_rows_available(self.k));
         }
@@ -207,6 +239,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
     }
     #[cfg(feature = "use_axiom_halo2_proofs")]
     fn assign_fixed(&mut self, column: Column<Fixed>, row: usize, to: Assigned<F>) {
+        if !self.records_layout() {
+            return;
+        }
 
         assert!(
             self.usable_rows.contains(&row),
@@ -239,6 +274,9 @@ impl<F: AnalyzableField> Assignment<F> for Analyzable<F> {
         right_column: Column<Any>,
         right_row: usize,
     ) -> Result<(), Error> {
+        if !self.records_layout() {
+            return Ok(());
+        }
         if !self.usable_rows.contains(&left_row) || !self.usable_rows.contains(&right_row) {
             return Err(Error::not_enough_rows_available(self.k));
         }
@@ -357,6 +395,31 @@ impl<'b, F: AnalyzableField> Analyzable<F> {
     fn in_phase<P: Phase>(&self, phase: P) -> bool {
         self.current_phase == phase.to_sealed()
     }
+
+    /// The circuit is synthesized once per phase, but regions, selectors, fixed cells and copies
+    /// are the same in every phase, so they are only recorded while synthesizing the first one.
+    #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs"))]
+    fn records_layout(&self) -> bool {
+        self.in_phase(FirstPhase)
+    }
+    #[cfg(not(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs")))]
+    fn records_layout(&self) -> bool {
+        true
+    }
+
+    /// Returns the phases of the circuit in order, up to the last phase an advice column is allocated in.
+    #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs"))]
+    fn phases(cs: &ConstraintSystem<F>) -> Vec<sealed::Phase> {
+        let last_phase = cs
+            .advice_column_phase()
+            .into_iter()
+            .max()
+            .unwrap_or_default();
+        [FirstPhase.to_sealed(), SecondPhase.to_sealed(), ThirdPhase.to_sealed()]
+            .into_iter()
+            .take(last_phase as usize + 1)
+            .collect()
+    }
     pub fn config_and_synthesize<ConcreteCircuit: Circuit<F>>(
         circuit: &ConcreteCircuit,
         k: u32,
@@ -392,6 +455,19 @@ impl<'b, F: AnalyzableField> Analyzable<F> {
             current_phase: FirstPhase.to_sealed(),
         };
 
+        // Synthesize every phase, so that the assignments of later phase advice columns, which may
+        // depend on the challenges, are made just like in the prover.
+        #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs"))]
+        for phase in Self::phases(&analyzable.cs) {
+            analyzable.current_phase = phase;
+            ConcreteCircuit::FloorPlanner::synthesize(
+                &mut analyzable,
+                circuit,
+                config.clone(),
+                constants.clone(),
+            )?;
+        }
+        #[cfg(not(any(feature = "use_pse_halo2_proofs", feature = "use_axiom_halo2_proofs",feature = "use_scroll_halo2_proofs")))]
         ConcreteCircuit::FloorPlanner::synthesize(&mut analyzable, circuit, config, constants)?;
 
         let (cs, selector_polys) = analyzable
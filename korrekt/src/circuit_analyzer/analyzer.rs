@@ -1,22 +1,36 @@
-use super::{analyzable::AnalyzableField, halo2_proofs_libs::*};
+use super::{
+    analyzable::{field_modulus, field_to_biguint, AnalyzableField},
+    halo2_proofs_libs::*,
+};
 use anyhow::{anyhow, Context, Result};
 use log::info;
+use num::{BigUint, Zero};
+use rand::{
+    rngs::{OsRng, StdRng},
+    RngCore, SeedableRng,
+};
+use rayon::prelude::*;
 
 use std::{
-    collections::{HashMap, HashSet},
-    fs::{self, File, OpenOptions},
-    path::Path,
-    process::Command,
+    collections::{BTreeMap, BTreeSet, HashMap, HashSet},
+    fmt,
+    fs::File,
+    ops::Range,
 };
 
-use crate::io::analyzer_io::{output_result, retrieve_user_input_for_underconstrained};
+use crate::io::analyzer_io::{
+    retrieve_user_input_for_specification, retrieve_user_input_for_underconstrained,
+};
 use crate::io::analyzer_io_type::{
-    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, VerificationMethod,
+    AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, ColumnLocation,
+    AnalyzerConfig, Assumption, CellLocation, CellScope, ChallengeValues, CellSelector, ColumnType, ConstraintOrigin, Counterexample,
+    Determinism, Finding, SpecEquation, SpecTerm, VerificationMethod, WitnessModel,
 };
 use crate::smt_solver::{
     smt,
-    smt::Printer,
-    smt_parser::{self, ModelResult, Satisfiability},
+    smt_parser::{ModelResult, Satisfiability, Variable},
+    artifacts::SmtArtifacts,
+    solver::{self, SmtSession},
 };
 use crate::{
     circuit_analyzer::abstract_expr::{self, AbsResult},
@@ -24,6 +38,7 @@ use crate::{
 };
 
 use super::analyzable::Analyzable;
+use super::builder::AnalyzerBuilder;
 
 #[derive(Debug)]
 pub struct Analyzer<F: AnalyzableField> {
@@ -33,9 +48,11 @@ pub struct Analyzer<F: AnalyzableField> {
 
     // The fixed cells in the circuit, arranged as [column][row].
     pub fixed: Vec<Vec<CellValue<F>>>,
-    pub fixed_converted: Vec<Vec<u64>>,
+    pub fixed_converted: Vec<Vec<BigUint>>,
 
     pub selectors: Vec<Vec<bool>>,
+    /// The rows that can be assigned, i.e. all rows except the blinding rows.
+    pub usable_rows: Range<usize>,
     pub log: Vec<String>,
     pub permutation: HashMap<String, String>,
     pub instace_cells: HashMap<String, i64>,
@@ -44,6 +61,26 @@ pub struct Analyzer<F: AnalyzableField> {
     pub lookup_mappings: Vec<HashMap<String, usize>>,
 
     pub lookup_tables: Vec<LookupTable>,
+    /// Options of the under-constrained analysis.
+    pub config: AnalyzerConfig,
+    /// The advice cells that must be unique for the circuit not to be under-constrained, every advice cell if empty.
+    ///
+    /// Other advice cells may then take several values, e.g. the hints of a gadget.
+    pub output_cells: Vec<CellSelector>,
+    /// The advice cells that are intentional hints, free to take several values.
+    ///
+    /// They are neither reported as unconstrained cells nor required to be unique.
+    pub hint_cells: Vec<CellSelector>,
+    /// The preconditions under which the public input must determine the witness.
+    pub assumptions: Vec<Assumption>,
+    /// The relations between cells that the constraints must imply, checked by `analyze_specification`.
+    pub specifications: Vec<Specification<F>>,
+    /// The values the challenges were fixed to by the last analysis, `None` if the circuit has no challenge.
+    pub challenges: Option<ChallengeValues>,
+    /// The variables first declared by the symbolically evaluated lookup tables of the last analysis.
+    ///
+    /// They only occur in table rows, so they are not required to be unique.
+    pub table_variables: HashSet<String>,
 }
 #[derive(Debug)]
 pub enum NodeType {
@@ -77,7 +114,32 @@ pub struct LookupTable {
     pub function_name: String,
     pub function_body: String,
     pub num_of_columns: usize,
-    pub fixed: Vec<Vec<u64>>,
+    pub fixed: Vec<Vec<BigUint>>,
+}
+
+/// A relation between cells that the constraints of a circuit are expected to imply.
+#[derive(Debug, Clone)]
+pub enum Specification<F: AnalyzableField> {
+    /// An equation of the specification language, e.g. `I-0-0 = A-0-0 * A-1-0 + A-2-0`.
+    Equation(SpecEquation),
+    /// `lhs = rhs`, for halo2 expressions queried relative to the absolute row `row`, e.g. expressions kept in the
+    /// configuration of a chip.
+    Expressions {
+        lhs: Expression<F>,
+        rhs: Expression<F>,
+        row: usize,
+    },
+}
+
+impl<F: AnalyzableField> fmt::Display for Specification<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Specification::Equation(equation) => write!(f, "{}", equation),
+            Specification::Expressions { lhs, rhs, row } => {
+                write!(f, "{:?} = {:?} at row {}", lhs, rhs, row)
+            }
+        }
+    }
 }
 
 impl<'b, F: AnalyzableField> Analyzer<F> {
@@ -86,9 +148,9 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         k: u32,
     ) -> Result<Self, Error> {
         let analyzable = Analyzable::config_and_synthesize(circuit, k)?;
-        let (permutation, instace_cells, cell_to_cycle_head) =
+        let (permutation, mut instace_cells, cell_to_cycle_head) =
             Analyzer::<F>::extract_permutations(&analyzable.permutation);
-        // Convert fixed to an equivalent matrix with u64 type instead of CellValue
+        // Convert fixed to an equivalent matrix of integers instead of CellValue
         let mut fixed = Vec::new();
         for col in analyzable.fixed.iter() {
             let mut new_col = Vec::new();
@@ -101,12 +163,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
 
                 match cell {
                     CellValue::Assigned(fixed_val) => {
-                        let t = u64::from_str_radix(
-                            format!("{:?}", fixed_val).strip_prefix("0x").unwrap(),
-                            16,
-                        )
-                        .unwrap();
-                        new_col.push(t);
+                        new_col.push(field_to_biguint(fixed_val));
                     }
                     CellValue::Unassigned => {
                         should_add = false; // Stop adding after encountering the first Unassigned
@@ -119,6 +176,14 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
 
             fixed.push(new_col);
         }
+        Analyzer::<F>::extract_instance_queries(
+            &analyzable.cs,
+            &analyzable.regions,
+            &analyzable.selectors,
+            &fixed,
+            &cell_to_cycle_head,
+            &mut instace_cells,
+        );
 
         Ok(Analyzer {
             cs: analyzable.cs,
@@ -126,6 +191,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             fixed: analyzable.fixed,
             fixed_converted: fixed,
             selectors: analyzable.selectors,
+            usable_rows: analyzable.usable_rows,
             log: Vec::new(),
             permutation,
             instace_cells,
@@ -133,20 +199,36 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             counter: 0,
             lookup_mappings: Vec::new(),
             lookup_tables: Vec::new(),
+            config: AnalyzerConfig::default(),
+            output_cells: Vec::new(),
+            hint_cells: Vec::new(),
+            assumptions: Vec::new(),
+            specifications: Vec::new(),
+            challenges: None,
+            table_variables: HashSet::new(),
         })
     }
 
+    /// Returns a builder of an analyzer of `circuit`, to set its options before the circuit is synthesized.
+    pub fn builder<ConcreteCircuit: Circuit<F>>(
+        circuit: &ConcreteCircuit,
+        k: u32,
+    ) -> AnalyzerBuilder<'_, F, ConcreteCircuit> {
+        AnalyzerBuilder::new(circuit, k)
+    }
+
     /// Detects unused custom gates
     ///
     /// This function iterates through the gates in the constraint system (`self.cs`) and checks if each gate is used.
     /// A gate is considered unused if it evaluates to zero for all regions in the layouter (`self.layouter`).
-    /// If an unused gate is found, it is logged in the `self.log` vector along with a suggested action.
-    /// Finally, the function prints the total number of unused gates found.
+    /// If an unused gate is found, it is reported as a `Finding::UnusedGate` and logged in the `self.log` vector
+    /// along with a suggested action.
+    /// Finally, the function logs the total number of unused gates found.
     ///
     pub fn analyze_unused_custom_gates(&mut self) -> Result<AnalyzerOutput> {
-        let mut count = 0;
+        let mut findings = vec![];
         let mut used;
-        for gate in self.cs.gates.iter() {
+        for (gate_index, gate) in self.cs.gates.iter().enumerate() {
             used = false;
 
             // is this gate identically zero over regions?
@@ -179,31 +261,36 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             }
 
             if !used {
-                count += 1;
-                self.log.push(format!("unused gate: \"{}\" (consider removing the gate or checking selectors in regions)", gate.name()));
+                findings.push(Finding::UnusedGate {
+                    gate_index,
+                    gate_name: gate.name().to_owned(),
+                });
             }
         }
-        println!("Finished analysis: {} unused gates found.", count);
-        if count > 0 {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::UnusedCustomGates,
-            })
+        self.log.extend(findings.iter().map(Finding::to_string));
+        info!("Finished analysis: {} unused gates found.", findings.len());
+        let output_status = if findings.is_empty() {
+            AnalyzerOutputStatus::NoUnusedCustomGates
         } else {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::NoUnusedCustomGates,
-            })
-        }
+            AnalyzerOutputStatus::UnusedCustomGates
+        };
+        Ok(AnalyzerOutput {
+            output_status,
+            findings,
+            assumptions: vec![],
+            challenges: None,
+        })
     }
 
     /// Detects unused columns
     ///
     /// This function iterates through the advice queries in the constraint system (`self.cs`) and checks if each column is used.
     /// A column is considered unused if it does not appear in any of the polynomials within the gates of the constraint system.
-    /// If an unused column is found, it is logged in the `self.log` vector.
-    /// Finally, the function prints the total number of unused columns found.
+    /// If an unused column is found, it is reported as a `Finding::UnusedColumn` and logged in the `self.log` vector.
+    /// Finally, the function logs the total number of unused columns found.
     ///
     pub fn analyze_unused_columns(&mut self) -> Result<AnalyzerOutput> {
-        let mut count = 0;
+        let mut findings = vec![];
         let mut used;
         for (column, rotation) in self.cs.advice_queries.iter().cloned() {
             used = false;
@@ -218,28 +305,41 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             }
 
             if !used {
-                count += 1;
-                self.log.push(format!("unused column: {:?}", column));
+                findings.push(Finding::UnusedColumn {
+                    column: ColumnLocation {
+                        column_type: ColumnType::Advice,
+                        index: column.index(),
+                    },
+                    rotation: rotation.0,
+                });
             }
         }
-        println!("Finished analysis: {} unused columns found.", count);
-        if count > 0 {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::UnusedColumns,
-            })
+        self.log.extend(findings.iter().map(Finding::to_string));
+        info!(
+            "Finished analysis: {} unused columns found.",
+            findings.len()
+        );
+        let output_status = if findings.is_empty() {
+            AnalyzerOutputStatus::NoUnusedColumns
         } else {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::NoUnusedColumns,
-            })
-        }
+            AnalyzerOutputStatus::UnusedColumns
+        };
+        Ok(AnalyzerOutput {
+            output_status,
+            findings,
+            assumptions: vec![],
+            challenges: None,
+        })
     }
 
     /// Detect assigned but unconstrained cells:
     /// (does it occur in a not-identially zero polynomial in the region?)
     /// (if not almost certainly a bug)
+    /// Every such cell is reported as a `Finding::UnconstrainedCell`.
     pub fn analyze_unconstrained_cells(&mut self) -> Result<AnalyzerOutput> {
-        let mut count = 0;
-        for region in self.regions.iter() {
+        let hints = self.selected_cells(&self.hint_cells);
+        let mut findings = vec![];
+        for (region_index, region) in self.regions.iter().enumerate() {
             let selectors = region.enabled_selectors.keys().cloned().collect();
             let (region_begin, region_end) = region.rows.unwrap();
             let row_num = (region_end - region_begin + 1) as i32;
@@ -251,9 +351,9 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     feature = "use_scroll_halo2_proofs",
                     feature = "use_pse_v1_halo2_proofs"
                 ))]
-                let (reg_column, rotation) = (cell.0 .0, cell.1);
+                let (reg_column, rotation, row) = (cell.0 .0, cell.1, cell.0 .1);
                 #[cfg(feature = "use_zcash_halo2_proofs")]
-                let (reg_column, rotation) = (cell.0, cell.1);
+                let (reg_column, rotation, row) = (cell.0, cell.1, cell.1);
                 used = false;
                 match reg_column.column_type {
                     Any::Fixed => continue,
@@ -283,21 +383,51 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     }
                 }
 
+                let column = Self::column_location(&reg_column);
+                // Intentional hints are free by design.
+                if matches!(column.column_type, ColumnType::Advice)
+                    && hints.contains(&format!("A-{}-{}", column.index, row))
+                {
+                    continue;
+                }
                 if !used {
-                    count += 1;
-                    self.log.push(format!("unconstrained cell in \"{}\" region: {:?} (rotation: {:?}) -- very likely a bug.", region.name,  reg_column, rotation));
+                    findings.push(Finding::UnconstrainedCell {
+                        region_index,
+                        region_name: region.name.clone(),
+                        column,
+                        row,
+                        rotation: row as i32 - region_begin as i32,
+                    });
                 }
             }
         }
-        println!("Finished analysis: {} unconstrained cells found.", count);
-        if count > 0 {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::UnconstrainedCells,
-            })
+        self.log.extend(findings.iter().map(Finding::to_string));
+        info!(
+            "Finished analysis: {} unconstrained cells found.",
+            findings.len()
+        );
+        let output_status = if findings.is_empty() {
+            AnalyzerOutputStatus::NoUnconstrainedCells
         } else {
-            Ok(AnalyzerOutput {
-                output_status: AnalyzerOutputStatus::NoUnconstrainedCells,
-            })
+            AnalyzerOutputStatus::UnconstrainedCells
+        };
+        Ok(AnalyzerOutput {
+            output_status,
+            findings,
+            assumptions: vec![],
+            challenges: None,
+        })
+    }
+
+    fn column_location(column: &Column<Any>) -> ColumnLocation {
+        let column_type = match column.column_type() {
+            Any::Fixed => ColumnType::Fixed,
+            Any::Instance => ColumnType::Instance,
+            _ => ColumnType::Advice,
+        };
+        ColumnLocation {
+            column_type,
+            index: column.index(),
         }
     }
     // Define a function to extract permutations from the provided permutation assembly.
@@ -418,32 +548,242 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         (pairs, instances, cell_to_cycle_head)
     }
 
+    /// Collects the instance cells that are queried directly by the custom gates.
+    ///
+    /// Instance cells that are not part of a copy constraint are not found by `extract_permutations`,
+    /// but they are public inputs all the same and have to be fixed by the uniqueness check.
+    /// Every cell is named the same way `decompose_expression` names it and is added to `instance_cells`.
+    /// Like `decompose_expression`, the right factor of a product is skipped on the rows where the left factor,
+    /// typically the selector of the gate, is zero, so that no instance cell is collected from a disabled gate.
+    pub fn extract_instance_queries(
+        cs: &ConstraintSystem<F>,
+        regions: &[Region],
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
+        cell_to_cycle_head: &HashMap<String, String>,
+        instance_cells: &mut HashMap<String, i64>,
+    ) {
+        for region in regions {
+            if region.enabled_selectors.is_empty() {
+                continue;
+            }
+            let (region_begin, region_end) = region.rows.unwrap();
+            for row_num in 0..region_end - region_begin + 1 {
+                for gate in cs.gates.iter() {
+                    for poly in &gate.polys {
+                        Self::extract_instance_queries_from_expression(
+                            poly,
+                            region_begin,
+                            i32::try_from(row_num).ok().unwrap(),
+                            selectors,
+                            fixed,
+                            cell_to_cycle_head,
+                            instance_cells,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn extract_instance_queries_from_expression(
+        poly: &Expression<F>,
+        region_begin: usize,
+        row_num: i32,
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
+        cell_to_cycle_head: &HashMap<String, String>,
+        instance_cells: &mut HashMap<String, i64>,
+    ) {
+        match poly {
+            Expression::Instance(instance_query) => {
+                let term = format!(
+                    "I-{}-{}",
+                    instance_query.column_index,
+                    instance_query.rotation.0 + row_num + region_begin as i32
+                );
+                let t = cell_to_cycle_head.get(&term).cloned().unwrap_or(term);
+                instance_cells.entry(t).or_insert(0);
+            }
+            Expression::Negated(a) | Expression::Scaled(a, _) => {
+                Self::extract_instance_queries_from_expression(
+                    a,
+                    region_begin,
+                    row_num,
+                    selectors,
+                    fixed,
+                    cell_to_cycle_head,
+                    instance_cells,
+                );
+            }
+            Expression::Sum(a, b) | Expression::Product(a, b) => {
+                Self::extract_instance_queries_from_expression(
+                    a,
+                    region_begin,
+                    row_num,
+                    selectors,
+                    fixed,
+                    cell_to_cycle_head,
+                    instance_cells,
+                );
+                if matches!(poly, Expression::Product(..))
+                    && Self::is_zero_at(a, region_begin, row_num, selectors, fixed)
+                {
+                    return;
+                }
+                Self::extract_instance_queries_from_expression(
+                    b,
+                    region_begin,
+                    row_num,
+                    selectors,
+                    fixed,
+                    cell_to_cycle_head,
+                    instance_cells,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns whether `decompose_expression` decomposes `poly` at `row_num` of the region starting at
+    /// `region_begin` into zero, i.e. whether its `IsZeroExpression` is `Zero`.
+    fn is_zero_at(
+        poly: &Expression<F>,
+        region_begin: usize,
+        row_num: i32,
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
+    ) -> bool {
+        match poly {
+            Expression::Constant(a) => field_to_biguint(a).is_zero(),
+            Expression::Selector(a) => {
+                let row = region_begin + row_num as usize;
+                !selectors[a.0].get(row).copied().unwrap_or(false)
+            }
+            Expression::Fixed(fixed_query) => {
+                let row = (fixed_query.rotation.0 + row_num) as usize + region_begin;
+                fixed[fixed_query.column_index]
+                    .get(row)
+                    .map_or(true, |value| value.is_zero())
+            }
+            Expression::Negated(a) => Self::is_zero_at(a, region_begin, row_num, selectors, fixed),
+            Expression::Sum(a, b) => {
+                Self::is_zero_at(a, region_begin, row_num, selectors, fixed)
+                    && Self::is_zero_at(b, region_begin, row_num, selectors, fixed)
+            }
+            Expression::Product(a, b) => {
+                Self::is_zero_at(a, region_begin, row_num, selectors, fixed)
+                    || Self::is_zero_at(b, region_begin, row_num, selectors, fixed)
+            }
+            Expression::Scaled(a, c) => {
+                field_to_biguint(c).is_zero()
+                    || Self::is_zero_at(a, region_begin, row_num, selectors, fixed)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns whether an SMT variable stands for a challenge, which are encoded as `C-<index>`.
+    pub fn is_challenge(var: &str) -> bool {
+        var.starts_with("C-")
+    }
+
+    /// Fixes every challenge used by the gates to a random field element.
+    ///
+    /// Challenges are drawn by the verifier once the advice columns of the earlier phases are committed,
+    /// so the uniqueness check asks whether the witness is unique for a random challenge, instead of
+    /// letting the solver choose a degenerate one such as zero.
+    /// The values are drawn from `self.config.seed`, or from a fresh seed, and are kept in `self.challenges`
+    /// to be reported with the result.
+    fn assert_random_challenges(&mut self, printer: &mut smt::Printer<File>) {
+        let mut challenges: Vec<String> = printer
+            .vars
+            .keys()
+            .filter(|var| Self::is_challenge(var))
+            .cloned()
+            .collect();
+        challenges.sort();
+        if challenges.is_empty() {
+            self.challenges = None;
+            return;
+        }
+        let seed = self.config.seed.unwrap_or_else(|| OsRng.next_u64());
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut values = BTreeMap::new();
+        for challenge in challenges {
+            let value = field_to_biguint(&F::random(&mut rng));
+            info!(
+                "Challenge {} is set to {} (seed {})",
+                challenge, value, seed
+            );
+            smt::write_assert(
+                printer,
+                challenge.clone(),
+                value.to_string(),
+                NodeType::Instance,
+                Operation::Equal,
+            );
+            values.insert(challenge, value.to_string());
+        }
+        self.challenges = Some(ChallengeValues { seed, values });
+    }
+
     /// Analyzes underconstrained circuits and generates an analyzer output.
     ///
     /// This function performs the analysis of underconstrained circuits. It takes as input an `analyzer_input` struct
-    /// containing the required information for the analysis, and a `fixed` vector representing the vallues of fixed columns.
+    /// containing the required information for the analysis. The SMT formulas are over the field `F` of the circuit.
     /// The function creates an SMT file, decomposes the polynomials, writes the necessary variables and assertions, and runs
     /// the control uniqueness function to determine the `output_status` of the analysis.
     /// The analyzer output is returned as a `Result` indicating success or an error if the analysis fails.
     pub fn analyze_underconstrained(
         &mut self,
         analyzer_input: AnalyzerInput,
-        base_field_prime: &str,
     ) -> Result<AnalyzerOutput> {
-        fs::create_dir_all("src/output/").unwrap();
-        let smt_file_path = "src/output/out.smt2";
-        let mut smt_file =
-            std::fs::File::create(smt_file_path).context("Failed to create file!")?;
-        let mut printer = smt::write_start(&mut smt_file, base_field_prime.to_owned());
+        solver::check_config(&self.config)?;
+        let artifacts = SmtArtifacts::new(&self.config)?;
+        let mut smt_file = File::create(artifacts.smt_file_path())
+            .context("Failed to create file!")?;
+        let mut printer = smt::write_start(
+            &mut smt_file,
+            field_modulus::<F>().to_string(),
+            self.config.field_encoding,
+            self.config.solver,
+            self.config.unsat_cores,
+        );
+        let mut session = SmtSession::new(&self.config, &artifacts)
+            .context("Failed to start the SMT solver!")?;
 
-        let _ = Self::decompose_polynomial(self, &mut printer, &analyzer_input);
+        self.decompose_polynomial(&mut printer, &analyzer_input)?;
+        self.assert_random_challenges(&mut printer);
 
         let instance_string = analyzer_input.verification_input.instances_string.clone();
 
         let mut analyzer_output: AnalyzerOutput = AnalyzerOutput {
             output_status: AnalyzerOutputStatus::Invalid,
+            findings: vec![],
+            assumptions: self.assumptions.clone(),
+            challenges: self.challenges.clone(),
         };
 
+        self.write_copy_constraints(&mut printer);
+
+        let output_status: AnalyzerOutputStatus = Self::uniqueness_assertion(
+            self,
+            &mut session,
+            &instance_string,
+            &analyzer_input,
+            &mut printer,
+            &mut analyzer_output.findings,
+        )
+        .context("Failed to run control uniqueness function!")?;
+
+        analyzer_output.output_status = output_status;
+
+        Ok(analyzer_output)
+    }
+
+    /// Writes the copy constraints, every cell of a copy cycle being equal to the head of the cycle.
+    fn write_copy_constraints(&self, printer: &mut smt::Printer<File>) {
         for permutation in &self.permutation {
             let mut permutation_r = permutation.0.to_owned();
             let mut permutation_l = permutation.1.to_owned();
@@ -454,7 +794,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 permutation_r = self.cell_to_cycle_head[permutation.0].to_owned();
             }
 
-            smt::write_var(&mut printer, permutation_r.to_owned());
+            smt::write_var(printer, permutation_r.to_owned());
             if self
                 .cell_to_cycle_head
                 .contains_key(&permutation.1.to_owned())
@@ -463,19 +803,26 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             }
 
             if !permutation_l.eq(&permutation_r) {
-                smt::write_var(&mut printer, permutation_l.to_owned());
+                smt::write_var(printer, permutation_l.to_owned());
 
-                let neg = format!("(ff.neg {})", permutation_l);
+                let neg = format!("({})", smt::get_neg(printer, permutation_l.clone()));
                 let term = smt::write_term(
-                    &mut printer,
+                    printer,
                     "add".to_owned(),
                     permutation_r.to_owned(),
                     NodeType::Advice,
                     neg,
                     NodeType::Advice,
                 );
+                smt::set_origin(
+                    printer,
+                    Some(ConstraintOrigin::Copy {
+                        left: permutation.0.clone(),
+                        right: permutation.1.clone(),
+                    }),
+                );
                 smt::write_assert(
-                    &mut printer,
+                    printer,
                     term,
                     "0".to_owned(),
                     NodeType::Poly,
@@ -483,22 +830,845 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 );
             }
         }
+        smt::set_origin(printer, None);
+    }
 
-        let output_status: AnalyzerOutputStatus = Self::uniqueness_assertion(
-            self,
-            smt_file_path.to_owned(),
-            &instance_string,
-            &analyzer_input,
-            &mut printer,
-        )
-        .context("Failed to run control uniqueness function!")?;
+    /// Asserts the preconditions of `self.assumptions`.
+    ///
+    /// An assumption on a cell of a copy cycle is about the head of the cycle, the variable of the SMT query.
+    fn write_assumptions(&self, printer: &mut smt::Printer<File>) -> Result<()> {
+        for assumption in &self.assumptions {
+            let var = self.cell_variable(assumption.cell()).with_context(|| {
+                format!(
+                    "The assumption {} is not about an instance or assigned advice cell of the circuit!",
+                    assumption
+                )
+            })?;
+            smt::write_var(printer, var.clone());
+            smt::set_origin(
+                printer,
+                Some(ConstraintOrigin::Assumption {
+                    assumption: assumption.clone(),
+                }),
+            );
+            match assumption {
+                Assumption::Equal { value, .. } => smt::write_assert(
+                    printer,
+                    var,
+                    value.to_string(),
+                    NodeType::Instance,
+                    Operation::Equal,
+                ),
+                Assumption::NotEqual { value, .. } => smt::write_assert(
+                    printer,
+                    var,
+                    value.to_string(),
+                    NodeType::Instance,
+                    Operation::NotEqual,
+                ),
+                Assumption::OneOf { values, .. } => {
+                    let mut equalities = "".to_owned();
+                    for value in values {
+                        equalities.push_str(&smt::get_assert(
+                            printer,
+                            var.clone(),
+                            value.to_string(),
+                            NodeType::Instance,
+                            Operation::Equal,
+                        )?);
+                    }
+                    smt::write_assert_bool(printer, equalities, Operation::Or);
+                }
+                Assumption::BitLength { bits, .. } => smt::write_bit_length(printer, &var, *bits),
+            }
+        }
+        smt::set_origin(printer, None);
+        Ok(())
+    }
 
-        analyzer_output.output_status = output_status;
-        output_result(analyzer_input, &analyzer_output);
+    /// Returns the SMT variable of an instance (`I-col-row`) or assigned advice (`A-col-row`) cell, the head of its
+    /// copy cycle, or `None` if the circuit has no such cell.
+    fn cell_variable(&self, cell: &str) -> Option<String> {
+        let assigned = if cell.starts_with("I-") {
+            self.instace_cells.contains_key(cell)
+        } else {
+            let mut parts = cell.strip_prefix("A-").unwrap_or_default().split('-');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(column), Some(row), None) => match (column.parse(), row.parse()) {
+                    (Ok(column), Ok(row)) => !self
+                        .selected_cells(&[CellSelector::Cell { column, row }])
+                        .is_empty(),
+                    _ => false,
+                },
+                _ => false,
+            }
+        };
+        assigned.then(|| {
+            self.cell_to_cycle_head
+                .get(cell)
+                .cloned()
+                .unwrap_or_else(|| cell.to_owned())
+        })
+    }
+
+    /// Declares the instance cells and asserts that they equal the given public input.
+    fn write_instance_values(
+        printer: &mut smt::Printer<File>,
+        instance_cols_string: &HashMap<String, i64>,
+    ) {
+        for var in instance_cols_string {
+            // Declare the variables in the SMT formula.
+            smt::write_var(printer, var.0.to_owned());
+            smt::set_origin(
+                printer,
+                Some(ConstraintOrigin::PublicInput {
+                    cell: var.0.clone(),
+                    value: *var.1,
+                }),
+            );
+            // Write an assertion that each veriables equals the given value.
+            smt::write_assert(
+                printer,
+                var.0.clone(),
+                (*var.1).to_string(),
+                NodeType::Instance,
+                Operation::Equal,
+            );
+        }
+        smt::set_origin(printer, None);
+    }
 
+    /// Checks, for every advice cell of `scope`, whether its value is determined by the public input.
+    ///
+    /// The constraints are encoded as in `analyze_underconstrained`, with the public input fixed to the given values
+    /// (specific verification) or to the values of `iterations` models (random verification). For each public input,
+    /// a model gives a value to every cell, and the cell is free if the solver finds another model where it takes
+    /// another value. A cell is free if it is free for one of the public inputs, and determined if it is determined
+    /// for all of them.
+    /// The cells are checked in parallel with `rayon`, each thread querying a solver of its own that is given the
+    /// whole query once. Every cell of a copy cycle shares the result of the head of its cycle.
+    /// Lookups cannot be uninterpreted functions, since the models of the parallel queries are not checked against
+    /// the lookup tables.
+    /// Returns `None` if no witness satisfies the constraints for the public input.
+    pub fn cell_determinism(
+        &mut self,
+        analyzer_input: &AnalyzerInput,
+        scope: &CellScope,
+    ) -> Result<Option<BTreeMap<String, Determinism>>> {
+        if matches!(analyzer_input.lookup_method, LookupMethod::Uninterpreted) {
+            return Err(anyhow!(
+                "The cell determinism analysis does not support uninterpreted lookups, inline or interpret them!"
+            ));
+        }
+        solver::check_config(&self.config)?;
+        let artifacts = SmtArtifacts::new(&self.config)?;
+        let mut smt_file = File::create(artifacts.smt_file_path())
+            .context("Failed to create file!")?;
+        let mut printer = smt::write_start(
+            &mut smt_file,
+            field_modulus::<F>().to_string(),
+            self.config.field_encoding,
+            self.config.solver,
+            // No finding of this analysis comes from an unsat core.
+            false,
+        );
+        let mut session = SmtSession::new(&self.config, &artifacts)
+            .context("Failed to start the SMT solver!")?;
+
+        self.decompose_polynomial(&mut printer, analyzer_input)?;
+        self.assert_random_challenges(&mut printer);
+        self.write_copy_constraints(&mut printer);
+        self.write_assumptions(&mut printer)?;
+        let instance_cols_string = &analyzer_input.verification_input.instances_string;
+        let iterations = match analyzer_input.verification_method {
+            VerificationMethod::Specific => {
+                Self::write_instance_values(&mut printer, instance_cols_string);
+                1
+            }
+            VerificationMethod::Random => analyzer_input.verification_input.iterations,
+            VerificationMethod::Global => {
+                return Err(anyhow!(
+                    "The cell determinism analysis does not support the global verification method!"
+                ))
+            }
+        };
+
+        // Every cell of a cycle is checked through the head of the cycle, which is the variable of the SMT query.
+        let cells: BTreeMap<String, String> = self
+            .advice_cells(scope)
+            .into_iter()
+            .map(|cell| {
+                let head = self.cell_to_cycle_head.get(&cell).unwrap_or(&cell).clone();
+                (cell, head)
+            })
+            .collect();
+        let mut heads: BTreeMap<String, Determinism> = BTreeMap::new();
+        for head in cells.values() {
+            // A cell copied from an instance or a fixed cell is fixed with it.
+            if head.starts_with("A-") {
+                smt::write_var(&mut printer, head.clone());
+                heads.insert(head.clone(), Determinism::Determined);
+            }
+        }
+
+        let mut variables: HashSet<String> = printer.vars.keys().cloned().collect();
+        for i in 1..=iterations {
+            let model = Self::solve_and_get_model(&mut session, &variables)
+                .context("Failed to solve and get model!")?;
+            match &model.sat {
+                Satisfiability::Satisfiable => {}
+                // No more public inputs to check.
+                Satisfiability::Unsatisfiable if i > 1 => break,
+                Satisfiability::Unsatisfiable => return Ok(None),
+                Satisfiability::Unknown(reason) => {
+                    for determinism in heads.values_mut() {
+                        if matches!(determinism, Determinism::Determined) {
+                            *determinism = Determinism::Unknown {
+                                reason: reason.clone(),
+                            };
+                        }
+                    }
+                    break;
+                }
+            }
+
+            // Fix the public input of the model, unless it is already fixed.
+            smt::write_push(&mut printer, 1);
+            if matches!(
+                analyzer_input.verification_method,
+                VerificationMethod::Random
+            ) {
+                for var in instance_cols_string.keys() {
+                    Self::pin_to_model(&mut printer, var, &model)?;
+                }
+            }
+            let commands = std::fs::read_to_string(artifacts.smt_file_path())
+                .context("Failed to read SMT file!")?;
+            smt::write_pop(&mut printer, 1);
+
+            // Cells already known to be free need not be checked again.
+            let mut queries = vec![];
+            for (head, determinism) in &heads {
+                if !matches!(determinism, Determinism::Free) {
+                    let other_value = smt::get_assert(
+                        &mut printer,
+                        head.clone(),
+                        Self::model_value(&model, head)?,
+                        NodeType::Advice,
+                        Operation::NotEqual,
+                    )
+                    .context("Failled to generate assert!")?;
+                    queries.push((head.clone(), other_value));
+                }
+            }
+            if !queries.is_empty() {
+                let config = &self.config;
+                let chunk_size = queries.len().div_ceil(rayon::current_num_threads());
+                let results = queries
+                    .par_chunks(chunk_size)
+                    .map(|chunk| Self::check_cells_determined(config, &commands, chunk))
+                    .collect::<Result<Vec<_>>>()?;
+                for (head, determinism) in results.into_iter().flatten() {
+                    let current = heads.get_mut(&head).unwrap();
+                    // A free cell stays free, and an unknown answer only overrides a determined cell.
+                    if matches!(determinism, Determinism::Free)
+                        || matches!(current, Determinism::Determined)
+                    {
+                        *current = determinism;
+                    }
+                }
+            }
+
+            // Do not generate the same public input again.
+            if matches!(
+                analyzer_input.verification_method,
+                VerificationMethod::Random
+            ) {
+                let mut neg_model = "".to_owned();
+                for var in instance_cols_string.keys() {
+                    let sa = smt::get_assert(
+                        &mut printer,
+                        var.clone(),
+                        Self::model_value(&model, var)?,
+                        NodeType::Instance,
+                        Operation::NotEqual,
+                    )
+                    .context("Failled to generate assert!")?;
+                    neg_model.push_str(&sa);
+                }
+                smt::write_assert_bool(&mut printer, neg_model, Operation::Or);
+            }
+            variables = printer.vars.keys().cloned().collect();
+        }
+
+        Ok(Some(
+            cells
+                .into_iter()
+                .map(|(cell, head)| {
+                    let determinism = heads.get(&head).cloned().unwrap_or(Determinism::Determined);
+                    (cell, determinism)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Checks with a solver of its own whether each cell of `queries` can take another value than in a model.
+    ///
+    /// `commands` is the whole SMT query, and each query is the name of a cell and the assertion that the cell
+    /// does not take its value in the model.
+    fn check_cells_determined(
+        config: &AnalyzerConfig,
+        commands: &str,
+        queries: &[(String, String)],
+    ) -> Result<Vec<(String, Determinism)>> {
+        let artifacts = SmtArtifacts::new(config)?;
+        let mut solver =
+            solver::start_solver(config, &artifacts).context("Failed to start the SMT solver!")?;
+        solver.send(commands)?;
+        queries
+            .iter()
+            .map(|(cell, other_value)| {
+                solver.push()?;
+                solver.assert(other_value)?;
+                let sat = solver.check_sat()?;
+                solver.pop()?;
+                let determinism = match sat {
+                    Satisfiability::Satisfiable => Determinism::Free,
+                    Satisfiability::Unsatisfiable => Determinism::Determined,
+                    Satisfiability::Unknown(reason) => Determinism::Unknown { reason },
+                };
+                Ok((cell.clone(), determinism))
+            })
+            .collect()
+    }
+
+    /// Returns the advice cells of `scope` that are assigned in some region, except for the intentional hints.
+    fn advice_cells(&self, scope: &CellScope) -> BTreeSet<String> {
+        let hints = self.selected_cells(&self.hint_cells);
+        self.assigned_advice_cells(scope)
+            .into_iter()
+            .filter(|cell| !hints.contains(cell))
+            .collect()
+    }
+
+    /// Returns the advice cells of `scope` that are assigned in some region.
+    fn assigned_advice_cells(&self, scope: &CellScope) -> BTreeSet<String> {
+        let mut cells = BTreeSet::new();
+        for region in self.regions.iter() {
+            if matches!(scope, CellScope::Region(name) if *name != region.name) {
+                continue;
+            }
+            #[cfg(feature = "use_zcash_halo2_proofs")]
+            let region_cells = region.cells.iter().map(|(column, row)| (column, *row));
+            #[cfg(not(feature = "use_zcash_halo2_proofs"))]
+            let region_cells = region.cells.keys().map(|(column, row)| (column, *row));
+            for (column, row) in region_cells {
+                if !matches!(column.column_type(), Any::Advice { .. })
+                    || matches!(scope, CellScope::Column(index) if *index != column.index())
+                {
+                    continue;
+                }
+                cells.insert(format!("A-{}-{}", column.index(), row));
+            }
+        }
+        cells
+    }
+
+    /// Returns the advice cells assigned in some region that are selected by one of `selectors`.
+    fn selected_cells(&self, selectors: &[CellSelector]) -> BTreeSet<String> {
+        let mut cells = BTreeSet::new();
+        for region in self.regions.iter() {
+            #[cfg(feature = "use_zcash_halo2_proofs")]
+            let region_cells = region.cells.iter().map(|(column, row)| (column, *row));
+            #[cfg(not(feature = "use_zcash_halo2_proofs"))]
+            let region_cells = region.cells.keys().map(|(column, row)| (column, *row));
+            for (column, row) in region_cells {
+                if !matches!(column.column_type(), Any::Advice { .. }) {
+                    continue;
+                }
+                let selected = selectors.iter().any(|selector| match selector {
+                    CellSelector::Cell {
+                        column: index,
+                        row: cell_row,
+                    } => *index == column.index() && *cell_row == row,
+                    CellSelector::Column(index) => *index == column.index(),
+                    CellSelector::Region(name) => *name == region.name,
+                    CellSelector::Annotation(annotation) => {
+                        Self::column_annotation(region, column).as_ref() == Some(annotation)
+                    }
+                });
+                if selected {
+                    cells.insert(format!("A-{}-{}", column.index(), row));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Returns the variables of the SMT query that must be unique, `None` if every variable must be.
+    ///
+    /// These are the variables of the output cells (every variable but the instance cells and the challenges by
+    /// default), except for the intentional hints. The variable of a cell is the head of its copy cycle, so a hint
+    /// also exempts the cells copied from it. Outputs copied to an instance cell are fixed by the public input. The
+    /// advice cells of outputs that occur in no constraint are kept, although the query does not declare them, so
+    /// that the caller reports them. The variables that only occur in the rows of a symbolic lookup table are not
+    /// outputs either.
+    fn unique_variables(&self, variables: &HashSet<String>) -> Result<Option<HashSet<String>>> {
+        if self.output_cells.is_empty()
+            && self.hint_cells.is_empty()
+            && self.table_variables.is_empty()
+        {
+            return Ok(None);
+        }
+        let variables_of = |selectors: &[CellSelector], kind: &str| -> Result<HashSet<String>> {
+            let cells = self.selected_cells(selectors);
+            if cells.is_empty() {
+                return Err(anyhow!(
+                    "The {} cells {:?} select no advice cell of the circuit!",
+                    kind,
+                    selectors
+                ));
+            }
+            Ok(cells
+                .into_iter()
+                .map(|cell| self.cell_to_cycle_head.get(&cell).cloned().unwrap_or(cell))
+                .collect())
+        };
+        let outputs = if self.output_cells.is_empty() {
+            variables.clone()
+        } else {
+            variables_of(&self.output_cells, "output")?
+        };
+        let hints = if self.hint_cells.is_empty() {
+            HashSet::new()
+        } else {
+            variables_of(&self.hint_cells, "hint")?
+        };
+        Ok(Some(
+            outputs
+                .into_iter()
+                .filter(|var| {
+                    (variables.contains(var) || var.starts_with("A-"))
+                        && !var.starts_with("I-")
+                        && !Self::is_challenge(var)
+                        && !hints.contains(var)
+                        && !self.table_variables.contains(var)
+                })
+                .collect(),
+        ))
+    }
+
+    /// Runs the cell determinism analysis, see `cell_determinism`, and reports every free cell as a finding.
+    pub fn analyze_cell_determinism(
+        &mut self,
+        analyzer_input: AnalyzerInput,
+        scope: CellScope,
+    ) -> Result<AnalyzerOutput> {
+        let cells = self
+            .cell_determinism(&analyzer_input, &scope)
+            .context("Failed to check the determinism of the cells!")?;
+        let mut findings = vec![];
+        let output_status = match cells {
+            None => AnalyzerOutputStatus::Overconstrained,
+            Some(cells) => {
+                let mut reason = None;
+                for (cell, determinism) in &cells {
+                    match determinism {
+                        Determinism::Determined => {}
+                        Determinism::Free => findings.push(Finding::FreeCell {
+                            cell: self.locate_cell(cell),
+                        }),
+                        Determinism::Unknown { reason: unknown } => {
+                            reason.get_or_insert_with(|| unknown.clone());
+                        }
+                    }
+                }
+                info!(
+                    "Finished analysis: {} of {} cells are not determined by the public input.",
+                    findings.len(),
+                    cells.len()
+                );
+                match reason {
+                    _ if !findings.is_empty() => AnalyzerOutputStatus::FreeCells,
+                    Some(reason) => AnalyzerOutputStatus::Inconclusive { reason },
+                    None => AnalyzerOutputStatus::NoFreeCells,
+                }
+            }
+        };
+        self.log.extend(findings.iter().map(Finding::to_string));
+        let analyzer_output = AnalyzerOutput {
+            output_status,
+            findings,
+            assumptions: self.assumptions.clone(),
+            challenges: self.challenges.clone(),
+        };
         Ok(analyzer_output)
     }
 
+    /// Checks that the constraints of the circuit imply each of its specifications, see `Specification`.
+    ///
+    /// The constraints are encoded as in `analyze_underconstrained`, under the assumptions, with the public input
+    /// fixed to the given values (specific verification) or left free, in which case a single query covers every
+    /// public input (random and global verification). For each specification, the solver looks for a witness that
+    /// satisfies the constraints but not the specification, which is reported as a finding.
+    pub fn analyze_specification(
+        &mut self,
+        analyzer_input: AnalyzerInput,
+    ) -> Result<AnalyzerOutput> {
+        if self.specifications.is_empty() {
+            return Err(anyhow!("The circuit has no specification to check!"));
+        }
+        let analyzer_input = Self::specification_input(analyzer_input);
+        solver::check_config(&self.config)?;
+        let artifacts = SmtArtifacts::new(&self.config)?;
+        let mut smt_file = File::create(artifacts.smt_file_path())
+            .context("Failed to create file!")?;
+        let mut printer = smt::write_start(
+            &mut smt_file,
+            field_modulus::<F>().to_string(),
+            self.config.field_encoding,
+            self.config.solver,
+            self.config.unsat_cores,
+        );
+        let mut session = SmtSession::new(&self.config, &artifacts)
+            .context("Failed to start the SMT solver!")?;
+
+        self.decompose_polynomial(&mut printer, &analyzer_input)?;
+        self.assert_random_challenges(&mut printer);
+        self.write_copy_constraints(&mut printer);
+        self.write_assumptions(&mut printer)?;
+        if matches!(
+            analyzer_input.verification_method,
+            VerificationMethod::Specific
+        ) {
+            Self::write_instance_values(
+                &mut printer,
+                &analyzer_input.verification_input.instances_string,
+            );
+        }
+
+        let variables: HashSet<String> = printer.vars.keys().cloned().collect();
+        let model = Self::solve_and_get_model(&mut session, &variables)
+            .context("Failed to solve and get model!")?;
+        let mut findings = vec![];
+        let output_status = match model.sat {
+            // Every specification holds vacuously, which is most likely not what was meant.
+            Satisfiability::Unsatisfiable => {
+                findings.push(Finding::Overconstrained {
+                    conflicting_constraints: Self::conflicting_constraints(&mut session, &printer),
+                });
+                AnalyzerOutputStatus::Overconstrained
+            }
+            Satisfiability::Unknown(reason) => AnalyzerOutputStatus::Inconclusive { reason },
+            Satisfiability::Satisfiable => self
+                .check_specifications(
+                    &mut session,
+                    &mut printer,
+                    &analyzer_input.lookup_method,
+                    &mut findings,
+                )
+                .context("Failed to check the specifications!")?,
+        };
+        self.log.extend(findings.iter().map(Finding::to_string));
+        let analyzer_output = AnalyzerOutput {
+            output_status,
+            findings,
+            assumptions: self.assumptions.clone(),
+            challenges: self.challenges.clone(),
+        };
+        Ok(analyzer_output)
+    }
+
+    /// Returns the input that `analyze_specification` checks for `analyzer_input`: random verification is global
+    /// verification, a single query covering every public input.
+    pub fn specification_input(analyzer_input: AnalyzerInput) -> AnalyzerInput {
+        let mut analyzer_input = analyzer_input;
+        if matches!(
+            analyzer_input.verification_method,
+            VerificationMethod::Random
+        ) {
+            analyzer_input.verification_method = VerificationMethod::Global;
+        }
+        analyzer_input
+    }
+
+    /// Looks for a witness that violates each specification, one query at a time, and reports it as a finding.
+    ///
+    /// With uninterpreted functions for lookups, a witness that does not satisfy the lookups is no violation, and
+    /// makes the analysis inconclusive instead.
+    fn check_specifications(
+        &self,
+        session: &mut SmtSession,
+        printer: &mut smt::Printer<File>,
+        lookup_method: &LookupMethod,
+        findings: &mut Vec<Finding>,
+    ) -> Result<AnalyzerOutputStatus> {
+        let mut reason = None;
+        for specification in &self.specifications {
+            let declared = printer.vars.clone();
+            smt::write_push(printer, 1);
+            let holds = self
+                .write_specification(printer, specification)
+                .with_context(|| format!("Invalid specification {}!", specification))?;
+            smt::write_assert_term(printer, format!("(not {})", holds));
+            // The bits of the operands of `xor` are not part of the witness.
+            let variables: HashSet<String> = printer
+                .vars
+                .keys()
+                .filter(|var| !var.starts_with("S-"))
+                .cloned()
+                .collect();
+            let model = Self::solve_and_get_model(session, &variables)
+                .context("Failed to solve and get model!")?;
+            smt::write_pop(printer, 1);
+            // The variables declared since the push are gone with it.
+            printer.vars.retain(|var, _| declared.contains_key(var));
+            match &model.sat {
+                Satisfiability::Unsatisfiable => {
+                    info!("The specification {} holds.", specification);
+                    continue;
+                }
+                Satisfiability::Unknown(unknown) => {
+                    reason.get_or_insert_with(|| unknown.clone());
+                    continue;
+                }
+                Satisfiability::Satisfiable => {}
+            }
+            if matches!(lookup_method, LookupMethod::Uninterpreted) {
+                let mut lookups_successful = true;
+                for index in 0..self.lookup_mappings.len() {
+                    lookups_successful &= self
+                        .lookup(&model, index)
+                        .context("Failed to perform lookup")?;
+                }
+                if !lookups_successful {
+                    info!(
+                        "The witness violating {} does not satisfy the lookups!",
+                        specification
+                    );
+                    reason.get_or_insert_with(|| {
+                        "a violation does not satisfy the uninterpreted lookups".to_owned()
+                    });
+                    continue;
+                }
+            }
+            findings.push(Finding::SpecificationViolation {
+                specification: specification.to_string(),
+                model: Self::witness_model(&model),
+            });
+        }
+        Ok(match reason {
+            _ if !findings.is_empty() => AnalyzerOutputStatus::SpecificationViolated,
+            Some(reason) => AnalyzerOutputStatus::Inconclusive { reason },
+            None => AnalyzerOutputStatus::SpecificationHolds,
+        })
+    }
+
+    /// Writes the variables and assertions a specification needs, and returns the SMT term of the condition that it
+    /// holds: its two sides are equal and the operands of every `xor` fit in its bits.
+    fn write_specification(
+        &self,
+        printer: &mut smt::Printer<File>,
+        specification: &Specification<F>,
+    ) -> Result<String> {
+        match specification {
+            Specification::Equation(equation) => {
+                let mut bits = 0;
+                let mut ranges = vec![];
+                let lhs = self.spec_term(printer, &equation.lhs, &mut bits, &mut ranges)?;
+                let rhs = self.spec_term(printer, &equation.rhs, &mut bits, &mut ranges)?;
+                if ranges.is_empty() {
+                    return Ok(format!("(= {} {})", lhs, rhs));
+                }
+                Ok(format!("(and {} (= {} {}))", ranges.join(" "), lhs, rhs))
+            }
+            Specification::Expressions { lhs, rhs, row } => Ok(format!(
+                "(= {} {})",
+                self.expression_term(printer, lhs, *row),
+                self.expression_term(printer, rhs, *row),
+            )),
+        }
+    }
+
+    /// Returns the SMT term of a halo2 expression queried relative to `row`, an atom or a parenthesized term.
+    fn expression_term(
+        &self,
+        printer: &mut smt::Printer<File>,
+        expression: &Expression<F>,
+        row: usize,
+    ) -> String {
+        let (term, node_type, is_zero) = Self::decompose_expression(
+            expression,
+            printer,
+            row,
+            row,
+            0,
+            &self.selectors,
+            &self.fixed_converted,
+            &self.cell_to_cycle_head,
+        );
+        match (is_zero, node_type) {
+            (IsZeroExpression::Zero, _) => smt::get_constant(printer, "0".to_owned()),
+            (_, NodeType::Advice | NodeType::Instance | NodeType::Fixed | NodeType::Constant) => {
+                term
+            }
+            _ => format!("({})", term),
+        }
+    }
+
+    /// Returns the SMT term of a term of the specification language, an atom or a parenthesized term.
+    ///
+    /// `bits` counts the boolean variables `S-<index>` declared for the operands of `xor`, and the conditions that
+    /// these operands fit in the bits of their `xor` are added to `ranges`.
+    fn spec_term(
+        &self,
+        printer: &mut smt::Printer<File>,
+        term: &SpecTerm,
+        bits: &mut usize,
+        ranges: &mut Vec<String>,
+    ) -> Result<String> {
+        Ok(match term {
+            SpecTerm::Cell(cell) => {
+                let var = self.cell_variable(cell).with_context(|| {
+                    format!(
+                        "{} is not an instance or assigned advice cell of the circuit!",
+                        cell
+                    )
+                })?;
+                smt::write_var(printer, var.clone());
+                var
+            }
+            SpecTerm::Constant(value) => smt::get_constant(printer, value.to_string()),
+            SpecTerm::Neg(term) => {
+                let term = self.spec_term(printer, term, bits, ranges)?;
+                format!("({})", smt::get_neg(printer, term))
+            }
+            SpecTerm::Add(left, right)
+            | SpecTerm::Sub(left, right)
+            | SpecTerm::Mul(left, right) => {
+                let left = self.spec_term(printer, left, bits, ranges)?;
+                let mut right = self.spec_term(printer, right, bits, ranges)?;
+                if matches!(term, SpecTerm::Sub(..)) {
+                    right = format!("({})", smt::get_neg(printer, right));
+                }
+                let op = if matches!(term, SpecTerm::Mul(..)) {
+                    "mul"
+                } else {
+                    "add"
+                };
+                Self::spec_operation(printer, op, left, right)
+            }
+            SpecTerm::Xor {
+                left,
+                right,
+                bits: width,
+            } => {
+                if u64::from(*width) >= field_modulus::<F>().bits() {
+                    return Err(anyhow!(
+                        "The operands of {} do not fit in a field element!",
+                        term
+                    ));
+                }
+                let left = self.spec_term(printer, left, bits, ranges)?;
+                let right = self.spec_term(printer, right, bits, ranges)?;
+                let left_bits = Self::write_spec_bits(printer, &left, *width, bits, ranges);
+                let right_bits = Self::write_spec_bits(printer, &right, *width, bits, ranges);
+                let minus_two = smt::get_constant(printer, "-2".to_owned());
+                // a xor b = a + b - 2ab for bits a and b.
+                let xor_bits = left_bits
+                    .into_iter()
+                    .zip(right_bits)
+                    .map(|(a, b)| {
+                        let sum = Self::spec_operation(printer, "add", a.clone(), b.clone());
+                        let product = Self::spec_operation(printer, "mul", a, b);
+                        let carry =
+                            Self::spec_operation(printer, "mul", minus_two.clone(), product);
+                        Self::spec_operation(printer, "add", sum, carry)
+                    })
+                    .collect();
+                Self::binary_sum(printer, xor_bits)
+            }
+        })
+    }
+
+    /// Returns the parenthesized SMT term of `op` (`add` or `mul`) applied to two atoms or parenthesized terms.
+    fn spec_operation(
+        printer: &mut smt::Printer<File>,
+        op: &str,
+        left: String,
+        right: String,
+    ) -> String {
+        let term = smt::write_term(
+            printer,
+            op.to_owned(),
+            left,
+            NodeType::Advice,
+            right,
+            NodeType::Advice,
+        );
+        format!("({})", term)
+    }
+
+    /// Returns the SMT term of the sum of `bits[i] * 2^i`.
+    fn binary_sum(printer: &mut smt::Printer<File>, bits: Vec<String>) -> String {
+        let mut sum = smt::get_constant(printer, "0".to_owned());
+        for (i, bit) in bits.into_iter().enumerate() {
+            let weight = smt::get_constant(printer, (BigUint::from(1u8) << i).to_string());
+            let term = Self::spec_operation(printer, "mul", weight, bit);
+            sum = Self::spec_operation(printer, "add", sum, term);
+        }
+        sum
+    }
+
+    /// Declares boolean variables for the canonical binary decomposition of `term`, and returns its `width` lowest
+    /// bits.
+    ///
+    /// Every field element has exactly one decomposition whose value is less than the modulus, so `term` is not
+    /// restricted. The condition that `term` is less than 2^`width`, i.e. that its other bits are zero, is added
+    /// to `ranges`.
+    fn write_spec_bits(
+        printer: &mut smt::Printer<File>,
+        term: &str,
+        width: u32,
+        bits: &mut usize,
+        ranges: &mut Vec<String>,
+    ) -> Vec<String> {
+        let zero = smt::get_constant(printer, "0".to_owned());
+        let one = smt::get_constant(printer, "1".to_owned());
+        let modulus = field_modulus::<F>();
+        let mut decomposition = vec![];
+        // The bits are less than the bits of the modulus from the lowest bit up to each bit.
+        let mut less = "false".to_owned();
+        for i in 0..modulus.bits() {
+            let bit = format!("S-{}", bits);
+            *bits += 1;
+            smt::write_var(printer, bit.clone());
+            smt::write_assert_term(
+                printer,
+                format!("(or (= {} {}) (= {} {}))", bit, zero, bit, one),
+            );
+            less = if modulus.bit(i) {
+                format!("(or (= {} {}) {})", bit, zero, less)
+            } else {
+                format!("(and (= {} {}) {})", bit, zero, less)
+            };
+            decomposition.push(bit);
+        }
+        smt::write_assert_term(printer, less);
+        let sum = Self::binary_sum(printer, decomposition.clone());
+        smt::write_assert_term(printer, format!("(= {} {})", term, sum));
+        let mut high_bits: Vec<String> = decomposition
+            .split_off(width as usize)
+            .iter()
+            .map(|bit| format!("(= {} {})", bit, zero))
+            .collect();
+        ranges.push(match high_bits.len() {
+            1 => high_bits.remove(0),
+            _ => format!("(and {})", high_bits.join(" ")),
+        });
+        decomposition
+    }
+
     #[cfg(test)]
     pub fn log(&self) -> &[String] {
         &self.log
@@ -512,7 +1682,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
      * * `printer` - A mutable reference to a `Printer` instance which is used for writing the decomposed expression.
      * * `region_no` - An integer that represents the region number.
      * * `row_num` - An integer that represents the row number in region.
-     * * `es` - A reference to a `HashSet` of Strings representing enabled selectors. These selectors are checked during the decomposition.
+     * * `selectors` - The selector matrix of the circuit, arranged as [selector][row]. A selector is encoded as 1 only on the rows where it is enabled.
      *
      * # Returns
      *
@@ -532,34 +1702,38 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         region_begin: usize,
         region_end: usize,
         row_num: i32,
-        es: &HashMap<Selector, Vec<usize>>,
-        fixed: &Vec<Vec<u64>>,
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
         cell_to_cycle_head: &HashMap<String, String>,
     ) -> (String, NodeType, IsZeroExpression) {
         let mut is_zero_expression = IsZeroExpression::NonZero;
         match &poly {
             Expression::Constant(a) => {
-                let constant_decimal_value =
-                    u64::from_str_radix(format!("{:?}", a).strip_prefix("0x").unwrap(), 16)
-                        .unwrap();
+                let constant_decimal_value = field_to_biguint(a);
 
-                if constant_decimal_value == 0 {
+                if constant_decimal_value.is_zero() {
                     return (
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Constant,
                         IsZeroExpression::Zero,
                     );
                 }
 
-                let term = format!("(as ff{:?} F)", constant_decimal_value);
+                let term = smt::get_constant(printer, constant_decimal_value.to_string());
                 (term, NodeType::Constant, is_zero_expression)
             }
             Expression::Selector(a) => {
-                if es.contains_key(a) {
-                    ("(as ff1 F)".to_owned(), NodeType::Fixed, is_zero_expression)
+                // Selector queries always refer to the current row of the gate.
+                let row = region_begin + row_num as usize;
+                if selectors[a.0].get(row).copied().unwrap_or(false) {
+                    (
+                        smt::get_constant(printer, "1".to_owned()),
+                        NodeType::Fixed,
+                        is_zero_expression,
+                    )
                 } else {
                     (
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         IsZeroExpression::Zero,
                     )
@@ -569,10 +1743,11 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 let col = fixed_query.column_index;
                 let row = (fixed_query.rotation.0 + row_num) as usize + region_begin;
 
-                let t = fixed[col][row];
-                let term = format!("(as ff{:?} F)", t);
+                // Fixed cells that were never assigned hold zero.
+                let t = fixed[col].get(row).cloned().unwrap_or_default();
+                let term = smt::get_constant(printer, t.to_string());
 
-                if t == 0 {
+                if t.is_zero() {
                     is_zero_expression = IsZeroExpression::Zero;
                 }
 
@@ -591,8 +1766,18 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 smt::write_var(printer, t.to_string());
                 (t.to_string(), NodeType::Advice, is_zero_expression)
             }
-            Expression::Instance(_instance_query) => {
-                ("".to_owned(), NodeType::Instance, is_zero_expression)
+            Expression::Instance(instance_query) => {
+                let term = format!(
+                    "I-{}-{}",
+                    instance_query.column_index,
+                    instance_query.rotation.0 + row_num + region_begin as i32
+                );
+                let mut t = term.clone();
+                if cell_to_cycle_head.contains_key(&term) {
+                    t = cell_to_cycle_head[&term.clone()].to_string();
+                }
+                smt::write_var(printer, t.to_string());
+                (t.to_string(), NodeType::Instance, is_zero_expression)
             }
             Expression::Negated(poly) => {
                 let (node_str, node_type, is_zero_expression) = Self::decompose_expression(
@@ -601,13 +1786,13 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
                 if matches!(is_zero_expression, IsZeroExpression::Zero) {
                     return (
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         IsZeroExpression::Zero,
                     );
@@ -617,9 +1802,9 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     || matches!(node_type, NodeType::Fixed)
                     || matches!(node_type, NodeType::Constant))
                 {
-                    format!("ff.neg {}", node_str)
+                    smt::get_neg(printer, node_str)
                 } else {
-                    format!("ff.neg ({})", node_str)
+                    smt::get_neg(printer, format!("({})", node_str))
                 };
                 (term, NodeType::Negated, is_zero_expression)
             }
@@ -630,7 +1815,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
@@ -640,19 +1825,17 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
 
-                if matches!(left_is_zero, IsZeroExpression::Zero)
-                    && matches!(right_is_zero, IsZeroExpression::Zero)
-                {
-                    return (
-                        "as ff0 F".to_owned(),
-                        NodeType::Fixed,
-                        IsZeroExpression::Zero,
-                    );
+                // A zero operand is left out, which also covers both operands being zero.
+                if matches!(left_is_zero, IsZeroExpression::Zero) {
+                    return (node_str_right, nodet_type_right, right_is_zero);
+                }
+                if matches!(right_is_zero, IsZeroExpression::Zero) {
+                    return (node_str_left, nodet_type_left, left_is_zero);
                 }
 
                 let term = smt::write_term(
@@ -672,26 +1855,34 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
+                // Gates are usually written as `selector * constraint`. When the selector is disabled on
+                // this row, the cells of the constraint must not be declared, otherwise they would show
+                // up as unconstrained variables in the uniqueness check.
+                if matches!(left_is_zero, IsZeroExpression::Zero) {
+                    return (
+                        smt::get_constant(printer, "0".to_owned()),
+                        NodeType::Fixed,
+                        IsZeroExpression::Zero,
+                    );
+                }
                 let (node_str_right, nodet_type_right, right_is_zero) = Self::decompose_expression(
                     b,
                     printer,
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
 
-                if matches!(left_is_zero, IsZeroExpression::Zero)
-                    || matches!(right_is_zero, IsZeroExpression::Zero)
-                {
+                if matches!(right_is_zero, IsZeroExpression::Zero) {
                     return (
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         IsZeroExpression::Zero,
                     );
@@ -715,7 +1906,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
@@ -725,7 +1916,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     region_begin,
                     region_end,
                     row_num,
-                    es,
+                    selectors,
                     fixed,
                     cell_to_cycle_head,
                 );
@@ -733,7 +1924,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     || matches!(right_is_zero, IsZeroExpression::Zero)
                 {
                     return (
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         IsZeroExpression::Zero,
                     );
@@ -753,7 +1944,11 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 feature = "use_axiom_halo2_proofs",
                 feature = "use_scroll_halo2_proofs"
             ))]
-            Expression::Challenge(_poly) => ("".to_string(), NodeType::Fixed, is_zero_expression),
+            Expression::Challenge(challenge) => {
+                let term = format!("C-{}", challenge.index());
+                smt::write_var(printer, term.clone());
+                (term, NodeType::Instance, is_zero_expression)
+            }
         }
     }
 
@@ -763,8 +1958,8 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         region_begin: usize,
         region_end: usize,
         row_num: i32,
-        es: &HashMap<Selector, Vec<usize>>,
-        fixed: &Vec<Vec<u64>>,
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
         cell_to_cycle_head: &HashMap<String, String>,
     ) -> Result<(String, NodeType, String, IsZeroExpression)> {
         let is_zero_expression = IsZeroExpression::NonZero;
@@ -773,16 +1968,17 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 "Constant expression in lookup expression is invalid."
             )),
             Expression::Selector(a) => {
-                if es.contains_key(a) {
+                let row = region_begin + row_num as usize;
+                if selectors[a.0].get(row).copied().unwrap_or(false) {
                     Ok((
-                        "(as ff1 F)".to_owned(),
+                        smt::get_constant(printer, "1".to_owned()),
                         NodeType::Fixed,
                         "1".to_owned(),
                         IsZeroExpression::NonZero,
                     ))
                 } else {
                     Ok((
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         "0".to_owned(),
                         IsZeroExpression::Zero,
@@ -793,16 +1989,16 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 let col = fixed_query.column_index;
                 let row = (fixed_query.rotation.0 + row_num) as usize + region_begin;
 
-                let t = fixed[col][row];
-                if t == 0 {
+                let t = &fixed[col][row];
+                if t.is_zero() {
                     return Ok((
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         "0".to_owned(),
                         IsZeroExpression::Zero,
                     ));
                 }
-                let term = format!("(as ff{:?} F)", t);
+                let term = smt::get_constant(printer, t.to_string());
 
                 Ok((term, NodeType::Fixed, t.to_string(), is_zero_expression))
             }
@@ -857,7 +2053,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                         region_begin,
                         region_end,
                         row_num,
-                        es,
+                        selectors,
                         fixed,
                         cell_to_cycle_head,
                     ).with_context(|| format!("Failed to decompose the left side of the Product expression within region from row: {} to {}, at row: {}", region_begin, region_end, row_num))?;
@@ -868,7 +2064,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                         region_begin,
                         region_end,
                         row_num,
-                        es,
+                        selectors,
                         fixed,
                         cell_to_cycle_head,
                     ).with_context(|| format!("Failed to decompose the right side of the Product expression within region from row: {} to {}, at row: {}", region_begin, region_end, row_num))?;
@@ -882,7 +2078,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     || matches!(right_is_zero, IsZeroExpression::Zero)
                 {
                     return Ok((
-                        "as ff0 F".to_owned(),
+                        smt::get_constant(printer, "0".to_owned()),
                         NodeType::Fixed,
                         "0".to_owned(),
                         IsZeroExpression::Zero,
@@ -936,15 +2132,25 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 if !region.enabled_selectors.is_empty() {
                     let (region_begin, region_end) = region.rows.unwrap();
                     for row_num in 0..region_end - region_begin + 1 {
-                        for gate in self.cs.gates.iter() {
-                            for poly in &gate.polys {
+                        for (gate_index, gate) in self.cs.gates.iter().enumerate() {
+                            for (polynomial_index, poly) in gate.polys.iter().enumerate() {
+                                smt::set_origin(
+                                    printer,
+                                    Some(ConstraintOrigin::Gate {
+                                        gate_index,
+                                        gate_name: gate.name().to_owned(),
+                                        polynomial_index,
+                                        region_name: region.name.clone(),
+                                        row: region_begin + row_num,
+                                    }),
+                                );
                                 let (node_str, _, _) = Self::decompose_expression(
                                     poly,
                                     printer,
                                     region_begin,
                                     region_end,
                                     i32::try_from(row_num).ok().unwrap(),
-                                    &region.enabled_selectors,
+                                    &self.selectors,
                                     &self.fixed_converted,
                                     &self.cell_to_cycle_head,
                                 );
@@ -972,9 +2178,342 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             // Extract all lookup constraints
             self.decompose_lookups(printer)?;
         }
+        // Extract all shuffles
+        #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_scroll_halo2_proofs"))]
+        self.decompose_shuffles(printer)?;
+        smt::set_origin(printer, None);
         Ok(())
     }
 
+    /// Encodes the shuffle arguments as multiset equalities.
+    ///
+    /// A shuffle argument requires the rows of its input expressions to be a permutation of the rows of its
+    /// shuffle expressions, over all usable rows. The permutation is encoded with a boolean matrix: `M-<shuffle>-<i>-<j>`
+    /// holds if input row `i` is mapped to shuffle row `j`, in which case both rows must be equal. Every input row is
+    /// mapped to at least one shuffle row and every shuffle row is the image of at most one input row, which makes the
+    /// mapping a bijection.
+    ///
+    /// Rows on which both sides are identically zero (typically because their selectors are disabled) are matched
+    /// with each other up front, so that only the remaining rows need a mapping.
+    #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_scroll_halo2_proofs"))]
+    fn decompose_shuffles(&self, printer: &mut smt::Printer<File>) -> Result<(), anyhow::Error> {
+        let zero = smt::get_constant(printer, "0".to_owned());
+        for (shuffle_index, shuffle) in self.cs.shuffles().iter().enumerate() {
+            smt::set_origin(printer, Some(ConstraintOrigin::Shuffle { shuffle_index }));
+            let zero_row = vec![zero.clone(); shuffle.input_expressions().len()];
+            let input_rows: Vec<Vec<String>> = self
+                .usable_rows
+                .clone()
+                .map(|row| self.decompose_expressions_at_row(shuffle.input_expressions(), printer, row))
+                .filter(|terms| !terms.eq(&zero_row))
+                .collect();
+            let shuffle_rows: Vec<Vec<String>> = self
+                .usable_rows
+                .clone()
+                .map(|row| self.decompose_expressions_at_row(shuffle.shuffle_expressions(), printer, row))
+                .filter(|terms| !terms.eq(&zero_row))
+                .collect();
+
+            // Zero rows left over on one side have to be matched with non-zero rows of the other side.
+            let mut inputs: Vec<&Vec<String>> = input_rows.iter().collect();
+            let mut shuffled: Vec<&Vec<String>> = shuffle_rows.iter().collect();
+            while inputs.len() < shuffled.len() {
+                inputs.push(&zero_row);
+            }
+            while shuffled.len() < inputs.len() {
+                shuffled.push(&zero_row);
+            }
+
+            if inputs.len() == 1 {
+                for (input_term, shuffle_term) in inputs[0].iter().zip(shuffled[0].iter()) {
+                    smt::write_assert_term(printer, format!("(= {} {})", input_term, shuffle_term));
+                }
+                continue;
+            }
+
+            let mapping = |i: usize, j: usize| format!("M-{}-{}-{}", shuffle_index, i, j);
+            for (i, input) in inputs.iter().enumerate() {
+                let mut mapped_rows = "".to_owned();
+                for (j, shuffled_row) in shuffled.iter().enumerate() {
+                    smt::write_declare_fn(printer, mapping(i, j), "".to_owned(), "Bool".to_owned());
+                    for (input_term, shuffle_term) in input.iter().zip(shuffled_row.iter()) {
+                        smt::write_assert_term(
+                            printer,
+                            format!("(=> {} (= {} {}))", mapping(i, j), input_term, shuffle_term),
+                        );
+                    }
+                    mapped_rows.push_str(&format!("{} ", mapping(i, j)));
+                }
+                // Every input row is mapped to a shuffle row.
+                smt::write_assert_bool(printer, mapped_rows, Operation::Or);
+            }
+            // No two input rows are mapped to the same shuffle row.
+            for j in 0..shuffled.len() {
+                for i in 0..inputs.len() {
+                    for k in i + 1..inputs.len() {
+                        smt::write_assert_term(
+                            printer,
+                            format!("(not (and {} {}))", mapping(i, j), mapping(k, j)),
+                        );
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Decomposes `poly` at `row_num` of the region starting at `region_begin` into a self-contained SMT term,
+    /// which can be used as an operand of any SMT function.
+    fn decompose_term(
+        &self,
+        poly: &Expression<F>,
+        printer: &mut smt::Printer<File>,
+        region_begin: usize,
+        region_end: usize,
+        row_num: i32,
+    ) -> String {
+        let (node_str, node_type, is_zero) = Self::decompose_expression(
+            poly,
+            printer,
+            region_begin,
+            region_end,
+            row_num,
+            &self.selectors,
+            &self.fixed_converted,
+            &self.cell_to_cycle_head,
+        );
+        Self::smt_term(printer, node_str, node_type, is_zero)
+    }
+
+    /// Turns the result of `decompose_expression` into a self-contained SMT term.
+    fn smt_term(
+        printer: &mut smt::Printer<File>,
+        node_str: String,
+        node_type: NodeType,
+        is_zero: IsZeroExpression,
+    ) -> String {
+        if matches!(is_zero, IsZeroExpression::Zero) {
+            smt::get_constant(printer, "0".to_owned())
+        } else if matches!(
+            node_type,
+            NodeType::Advice | NodeType::Instance | NodeType::Fixed | NodeType::Constant
+        ) {
+            node_str
+        } else {
+            format!("({})", node_str)
+        }
+    }
+
+    /// Decomposes a lookup input expression for the `Uninterpreted` and `Interpreted` lookup methods.
+    ///
+    /// An input made of a single cell, possibly multiplied by selectors, is passed to the lookup function as is.
+    /// Any other polynomial, such as `s * (a - b)`, `s * (a + 256 * b)` or `(1 - s) * 0`, is bound to a fresh
+    /// variable `L-<n>` that is constrained to be equal to the decomposed expression, and the variable is passed
+    /// to the lookup function instead.
+    ///
+    /// Returns the same tuple as `decompose_lookup_expression`, where the third element is the variable to pass.
+    fn decompose_lookup_input(
+        poly: &Expression<F>,
+        printer: &mut smt::Printer<File>,
+        region_begin: usize,
+        region_end: usize,
+        row_num: i32,
+        selectors: &Vec<Vec<bool>>,
+        fixed: &Vec<Vec<BigUint>>,
+        cell_to_cycle_head: &HashMap<String, String>,
+        counter: &mut u32,
+    ) -> (String, NodeType, String, IsZeroExpression) {
+        if let Ok((node_str, node_type, var, is_zero)) = Self::decompose_lookup_expression(
+            poly,
+            printer,
+            region_begin,
+            region_end,
+            row_num,
+            selectors,
+            fixed,
+            cell_to_cycle_head,
+        ) {
+            if matches!(is_zero, IsZeroExpression::Zero) || printer.vars.contains_key(&var) {
+                return (node_str, node_type, var, is_zero);
+            }
+        }
+        let (node_str, node_type, is_zero) = Self::decompose_expression(
+            poly,
+            printer,
+            region_begin,
+            region_end,
+            row_num,
+            selectors,
+            fixed,
+            cell_to_cycle_head,
+        );
+        if matches!(is_zero, IsZeroExpression::Zero) {
+            return (
+                smt::get_constant(printer, "0".to_owned()),
+                NodeType::Fixed,
+                "0".to_owned(),
+                IsZeroExpression::Zero,
+            );
+        }
+        let var = format!("L-{}", counter);
+        *counter += 1;
+        smt::write_var(printer, var.clone());
+        let term = Self::smt_term(printer, node_str, node_type, is_zero);
+        smt::write_assert_term(printer, format!("(= {} {})", var, term));
+        (var.clone(), NodeType::Advice, var, IsZeroExpression::NonZero)
+    }
+
+    /// Decomposes every expression of a shuffle argument or a lookup table at an absolute row of the circuit.
+    fn decompose_expressions_at_row(
+        &self,
+        expressions: &[Expression<F>],
+        printer: &mut smt::Printer<File>,
+        row: usize,
+    ) -> Vec<String> {
+        expressions
+            .iter()
+            .map(|expression| {
+                self.decompose_term(
+                    expression,
+                    printer,
+                    0,
+                    self.usable_rows.end,
+                    i32::try_from(row).ok().unwrap(),
+                )
+            })
+            .collect()
+    }
+
+    /// Returns whether every table expression of a lookup is a fixed column, in which case the table is known
+    /// before the analysis and its rows are encoded as constants.
+    fn is_fixed_table(table_expressions: &[Expression<F>]) -> bool {
+        table_expressions
+            .iter()
+            .all(|expression| matches!(expression, Expression::Fixed(_)))
+    }
+
+    /// Evaluates the table expressions of a lookup symbolically on the rows that belong to the table.
+    ///
+    /// Used for tables that are not plain fixed columns, such as advice columns (dynamic lookups) or expressions
+    /// like `q * col`. A usable row belongs to the table if a selector or fixed column of the table is non-zero on
+    /// it, or if every advice cell the table queries on it is assigned, so the unassigned rows of an ungated advice
+    /// table are not free table entries. Every row of the result is a tuple of SMT terms, and identical rows, e.g.
+    /// all the rows on which the table selector is disabled, are only kept once.
+    /// The variables first declared by the table are added to `table_variables`.
+    fn symbolic_table_rows(
+        &self,
+        table_expressions: &[Expression<F>],
+        printer: &mut smt::Printer<File>,
+        table_variables: &mut HashSet<String>,
+    ) -> Vec<Vec<String>> {
+        let assigned = self.assigned_advice_cells(&CellScope::All);
+        let declared: HashSet<String> = printer.vars.keys().cloned().collect();
+        let mut rows: Vec<Vec<String>> = vec![];
+        for row in self.usable_rows.clone() {
+            let row_num = i32::try_from(row).ok().unwrap();
+            let mut cells = vec![];
+            let mut enabled = false;
+            for expression in table_expressions {
+                enabled |= self.table_row_cells(expression, row_num, &mut cells);
+            }
+            if !enabled && !cells.iter().all(|cell| assigned.contains(cell)) {
+                continue;
+            }
+            let terms = self.decompose_expressions_at_row(table_expressions, printer, row);
+            if !rows.contains(&terms) {
+                rows.push(terms);
+            }
+        }
+        table_variables.extend(
+            printer
+                .vars
+                .keys()
+                .filter(|var| !declared.contains(*var))
+                .cloned(),
+        );
+        rows
+    }
+
+    /// Collects the advice cells that a table expression queries at an absolute row, skipping the factors of a
+    /// product whose left factor is zero as `decompose_expression` does, and returns whether a selector or a fixed
+    /// column it queries is non-zero on this row.
+    fn table_row_cells(&self, poly: &Expression<F>, row_num: i32, cells: &mut Vec<String>) -> bool {
+        match poly {
+            Expression::Advice(advice_query) => {
+                cells.push(format!(
+                    "A-{}-{}",
+                    advice_query.column_index,
+                    advice_query.rotation.0 + row_num
+                ));
+                false
+            }
+            Expression::Selector(_) | Expression::Fixed(_) => {
+                !Self::is_zero_at(poly, 0, row_num, &self.selectors, &self.fixed_converted)
+            }
+            Expression::Negated(a) | Expression::Scaled(a, _) => {
+                self.table_row_cells(a, row_num, cells)
+            }
+            Expression::Sum(a, b) => {
+                let enabled = self.table_row_cells(a, row_num, cells);
+                self.table_row_cells(b, row_num, cells) || enabled
+            }
+            Expression::Product(a, b) => {
+                let enabled = self.table_row_cells(a, row_num, cells);
+                if Self::is_zero_at(a, 0, row_num, &self.selectors, &self.fixed_converted) {
+                    return enabled;
+                }
+                self.table_row_cells(b, row_num, cells) || enabled
+            }
+            _ => false,
+        }
+    }
+
+    /// Asserts that the input tuple of a lookup, evaluated at `row_num` of a region, is one of the rows of a
+    /// symbolic table, i.e. the disjunction over the table rows of the input being equal to the row.
+    /// Nothing is asserted if every input expression is zero on this row.
+    fn write_symbolic_lookup(
+        &self,
+        input_expressions: &[Expression<F>],
+        table_rows: &[Vec<String>],
+        printer: &mut smt::Printer<File>,
+        region_begin: usize,
+        region_end: usize,
+        row_num: i32,
+    ) {
+        let zero = smt::get_constant(printer, "0".to_owned());
+        let inputs: Vec<String> = input_expressions
+            .iter()
+            .map(|poly| self.decompose_term(poly, printer, region_begin, region_end, row_num))
+            .collect();
+        if inputs.iter().all(|input| input.eq(&zero)) {
+            return;
+        }
+        if table_rows.is_empty() {
+            // No row belongs to the table, so nothing can be looked up in it.
+            smt::write_assert_term(printer, "false".to_owned());
+            return;
+        }
+        let all = |terms: Vec<String>, op: &str| {
+            if terms.len() == 1 {
+                terms[0].clone()
+            } else {
+                format!("({} {})", op, terms.join(" "))
+            }
+        };
+        let rows: Vec<String> = table_rows
+            .iter()
+            .map(|table_row| {
+                let equalities = inputs
+                    .iter()
+                    .zip(table_row.iter())
+                    .map(|(input, table)| format!("(= {} {})", input, table))
+                    .collect();
+                all(equalities, "and")
+            })
+            .collect();
+        smt::write_assert_term(printer, all(rows, "or"));
+    }
+
     #[cfg(any(
         feature = "use_zcash_halo2_proofs",
         feature = "use_pse_halo2_proofs",
@@ -982,12 +2521,52 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         feature = "use_pse_v1_halo2_proofs",
     ))]
     // Extracts the lookup constraints and writes assertions using an SMT printer.
-    fn decompose_lookups(&self, printer: &mut smt::Printer<File>) -> Result<(), anyhow::Error> {
+    fn decompose_lookups(&mut self, printer: &mut smt::Printer<File>) -> Result<(), anyhow::Error> {
+        // Tables that are not made of fixed columns are evaluated symbolically once, and shared by all rows.
+        let mut table_variables = HashSet::new();
+        let symbolic_tables: Vec<Option<Vec<Vec<String>>>> = self
+            .cs
+            .lookups
+            .iter()
+            .map(|lookup| {
+                if Self::is_fixed_table(&lookup.table_expressions) {
+                    None
+                } else {
+                    Some(self.symbolic_table_rows(
+                        &lookup.table_expressions,
+                        printer,
+                        &mut table_variables,
+                    ))
+                }
+            })
+            .collect();
+        self.table_variables = table_variables;
         for region in &self.regions {
             if !region.enabled_selectors.is_empty() {
                 let (region_begin, region_end) = region.rows.unwrap();
                 for row_num in 0..region_end - region_begin + 1 {
-                    for lookup in self.cs.lookups.iter() {
+                    for (lookup_index, (lookup, symbolic_table)) in
+                        self.cs.lookups.iter().zip(&symbolic_tables).enumerate()
+                    {
+                        smt::set_origin(
+                            printer,
+                            Some(ConstraintOrigin::Lookup {
+                                lookup_index,
+                                region_name: region.name.clone(),
+                                row: region_begin + row_num,
+                            }),
+                        );
+                        if let Some(table_rows) = symbolic_table {
+                            self.write_symbolic_lookup(
+                                &lookup.input_expressions,
+                                table_rows,
+                                printer,
+                                region_begin,
+                                region_end,
+                                i32::try_from(row_num).ok().unwrap(),
+                            );
+                            continue;
+                        }
                         let mut zero_lookup_expressions = Vec::new();
                         let mut cons_str_vec = Vec::new();
                         for poly in &lookup.input_expressions {
@@ -997,7 +2576,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                                 region_begin,
                                 region_end,
                                 i32::try_from(row_num).ok().unwrap(),
-                                &region.enabled_selectors,
+                                &self.selectors,
                                 &self.fixed_converted,
                                 &self.cell_to_cycle_head,
                             );
@@ -1051,7 +2630,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     continue;
                 }
                 //*** Iterate over fixed cols */
-                let t = format!("{:?}", self.fixed_converted[col_indices[col]][row]);
+                let t = self.fixed_converted[col_indices[col]][row].to_string();
 
                 let sa = smt::get_assert(
                     printer,
@@ -1095,7 +2674,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             for col in 0..col_indices.len() {
                 let input = format!("x_{} ", col);
                 //*** Iterate over fixed cols */
-                let t = format!("{:?}", self.fixed_converted[col_indices[col]][row]);
+                let t = self.fixed_converted[col_indices[col]][row].to_string();
 
                 let sa = smt::get_assert(printer, input, t, NodeType::Advice, Operation::Equal)
                     .context("Failled to generate assert!")?;
@@ -1128,12 +2707,52 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         analyzer_input: &AnalyzerInput,
     ) -> Result<(), anyhow::Error> {
         let mut lookup_func_map = HashMap::new();
+        let mut table_variables = HashSet::new();
+        let symbolic_tables: Vec<Option<Vec<Vec<String>>>> = self
+            .cs
+            .lookups
+            .iter()
+            .map(|lookup| {
+                if Self::is_fixed_table(&lookup.table_expressions) {
+                    None
+                } else {
+                    Some(self.symbolic_table_rows(
+                        &lookup.table_expressions,
+                        printer,
+                        &mut table_variables,
+                    ))
+                }
+            })
+            .collect();
+        self.table_variables = table_variables;
         for region in &self.regions {
             if !region.enabled_selectors.is_empty() {
                 let (region_begin, region_end) = region.rows.unwrap();
                 for row_num in 0..region_end - region_begin + 1 {
                     let mut lookup_index = 0;
                     for lookup in self.cs.lookups.iter() {
+                        smt::set_origin(
+                            printer,
+                            Some(ConstraintOrigin::Lookup {
+                                lookup_index,
+                                region_name: region.name.clone(),
+                                row: region_begin + row_num,
+                            }),
+                        );
+                        // A symbolic table is not a function of the lookup inputs alone, so it is always
+                        // encoded inline, whatever the lookup method.
+                        if let Some(table_rows) = &symbolic_tables[lookup_index] {
+                            self.write_symbolic_lookup(
+                                &lookup.input_expressions,
+                                table_rows,
+                                printer,
+                                region_begin,
+                                region_end,
+                                i32::try_from(row_num).ok().unwrap(),
+                            );
+                            lookup_index += 1;
+                            continue;
+                        }
                         let mut function_name = String::new();
                         let mut matched_lookup_exists = false;
                         let mut cons_str_vec = Vec::new();
@@ -1141,17 +2760,17 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                         // Decompose the lookup input expressions and store the result in cons_str_vec.
                         for poly in &lookup.input_expressions {
                             // Decompose the lookup input expressions and return the SMT compatible decomposed expression and the variable name.
-                            let (node_str, _, var, is_zero) = Self::decompose_lookup_expression(
+                            let (node_str, _, var, is_zero) = Self::decompose_lookup_input(
                                 poly,
                                 printer,
                                 region_begin,
                                 region_end,
                                 i32::try_from(row_num).ok().unwrap(),
-                                &region.enabled_selectors,
+                                &self.selectors,
                                 &self.fixed_converted,
                                 &self.cell_to_cycle_head,
-                            )
-                        .with_context(|| format!("Failed to decompose lookup input expression within region from row: {} to {}, at row: {}", region_begin, region_end, row_num))?;
+                                &mut self.counter,
+                            );
                             if matches!(is_zero, IsZeroExpression::NonZero) {
                                 cons_str_vec.push(node_str);
                                 if !var.is_empty() {
@@ -1266,11 +2885,11 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                                     );
                                 } else {
                                     function_name = format!("isInLookupTable{}", lookup_index);
-                                    for cons_str in cons_str_vec.iter() {
+                                    for cell in lookup_arg_cells.iter() {
                                         smt::write_assert_boolean_func(
                                             printer,
                                             function_name.clone(),
-                                            format!("({})", cons_str).to_owned(),
+                                            cell.clone(),
                                         );
                                     }
                                 }
@@ -1290,30 +2909,67 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         analyzer_input: &AnalyzerInput,
     ) -> Result<(), anyhow::Error> {
         let mut lookup_func_map = HashMap::new();
+        let mut table_variables = HashSet::new();
+        let symbolic_tables: Vec<Option<Vec<Vec<String>>>> = self
+            .cs
+            .lookups_map
+            .iter()
+            .map(|lookup| {
+                if Self::is_fixed_table(&lookup.1.table) {
+                    None
+                } else {
+                    Some(self.symbolic_table_rows(&lookup.1.table, printer, &mut table_variables))
+                }
+            })
+            .collect();
+        self.table_variables = table_variables;
         for region in &self.regions {
             if !region.enabled_selectors.is_empty() {
                 let (region_begin, region_end) = region.rows.unwrap();
                 for row_num in 0..region_end - region_begin + 1 {
                     let mut lookup_index = 0;
                     for lookup in &self.cs.lookups_map {
+                        smt::set_origin(
+                            printer,
+                            Some(ConstraintOrigin::Lookup {
+                                lookup_index,
+                                region_name: region.name.clone(),
+                                row: region_begin + row_num,
+                            }),
+                        );
+                        // A symbolic table is not a function of the lookup inputs alone, so it is always
+                        // encoded inline, whatever the lookup method.
+                        if let Some(table_rows) = &symbolic_tables[lookup_index] {
+                            for inputs in &lookup.1.inputs {
+                                self.write_symbolic_lookup(
+                                    inputs,
+                                    table_rows,
+                                    printer,
+                                    region_begin,
+                                    region_end,
+                                    i32::try_from(row_num).ok().unwrap(),
+                                );
+                            }
+                            lookup_index += 1;
+                            continue;
+                        }
                         let mut function_name = String::new();
                         let mut matched_lookup_exists = false;
                         let mut cons_str_vec = Vec::new();
                         let mut lookup_arg_cells = Vec::new();
                         for polys in &lookup.1.inputs {
                             for poly in polys {
-                                let (node_str, _, var, is_zero) =
-                                    Self::decompose_lookup_expression(
-                                        poly,
-                                        printer,
-                                        region_begin,
-                                        region_end,
-                                        i32::try_from(row_num).ok().unwrap(),
-                                        &region.enabled_selectors,
-                                        &self.fixed_converted,
-                                        &self.cell_to_cycle_head,
-                                    )
-                                    .with_context(|| format!("Failed to decompose lookup input expression within region from row: {} to {}, at row: {}", region_begin, region_end, row_num))?;
+                                let (node_str, _, var, is_zero) = Self::decompose_lookup_input(
+                                    poly,
+                                    printer,
+                                    region_begin,
+                                    region_end,
+                                    i32::try_from(row_num).ok().unwrap(),
+                                    &self.selectors,
+                                    &self.fixed_converted,
+                                    &self.cell_to_cycle_head,
+                                    &mut self.counter,
+                                );
                                 if matches!(is_zero, IsZeroExpression::NonZero) {
                                     cons_str_vec.push(node_str);
                                     if !var.is_empty() {
@@ -1424,11 +3080,11 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                                     );
                                 } else {
                                     function_name = format!("isInLookupTable{}", lookup_index);
-                                    for cons_str in cons_str_vec.iter() {
+                                    for cell in lookup_arg_cells.iter() {
                                         smt::write_assert_boolean_func(
                                             printer,
                                             function_name.clone(),
-                                            format!("({})", cons_str).to_owned(),
+                                            cell.clone(),
                                         );
                                     }
                                 }
@@ -1442,12 +3098,50 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         Ok(())
     }
     #[cfg(any(feature = "use_scroll_halo2_proofs"))]
-    fn decompose_lookups(&self, printer: &mut smt::Printer<File>) -> Result<(), anyhow::Error> {
+    fn decompose_lookups(&mut self, printer: &mut smt::Printer<File>) -> Result<(), anyhow::Error> {
+        // Tables that are not made of fixed columns are evaluated symbolically once, and shared by all rows.
+        let mut table_variables = HashSet::new();
+        let symbolic_tables: Vec<Option<Vec<Vec<String>>>> = self
+            .cs
+            .lookups_map
+            .iter()
+            .map(|lookup| {
+                if Self::is_fixed_table(&lookup.1.table) {
+                    None
+                } else {
+                    Some(self.symbolic_table_rows(&lookup.1.table, printer, &mut table_variables))
+                }
+            })
+            .collect();
+        self.table_variables = table_variables;
         for region in &self.regions {
             if !region.enabled_selectors.is_empty() {
                 let (region_begin, region_end) = region.rows.unwrap();
                 for row_num in 0..region_end - region_begin + 1 {
-                    for lookup in &self.cs.lookups_map {
+                    for (lookup_index, (lookup, symbolic_table)) in
+                        self.cs.lookups_map.iter().zip(&symbolic_tables).enumerate()
+                    {
+                        smt::set_origin(
+                            printer,
+                            Some(ConstraintOrigin::Lookup {
+                                lookup_index,
+                                region_name: region.name.clone(),
+                                row: region_begin + row_num,
+                            }),
+                        );
+                        if let Some(table_rows) = symbolic_table {
+                            for inputs in &lookup.1.inputs {
+                                self.write_symbolic_lookup(
+                                    inputs,
+                                    table_rows,
+                                    printer,
+                                    region_begin,
+                                    region_end,
+                                    i32::try_from(row_num).ok().unwrap(),
+                                );
+                            }
+                            continue;
+                        }
                         let mut zero_lookup_expressions = Vec::new();
                         let mut cons_str_vec = Vec::new();
                         for polys in &lookup.1.inputs {
@@ -1458,7 +3152,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                                     region_begin,
                                     region_end,
                                     i32::try_from(row_num).ok().unwrap(),
-                                    &region.enabled_selectors,
+                                    &self.selectors,
                                     &self.fixed_converted,
                                     &self.cell_to_cycle_head,
                                 );
@@ -1500,19 +3194,23 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
     /// and constraints. It iterates over the variables and applies different rules based on the verification method
     /// specified in the `analyzer_input`. The function writes assertions using an SMT printer and returns the
     /// analysis result as `AnalyzerOutputStatus`.
+    /// If the circuit is under-constrained, the two witnesses found are pushed to `findings`.
     ///
     pub fn uniqueness_assertion(
         &mut self,
-        smt_file_path: String,
+        session: &mut SmtSession,
         instance_cols_string: &HashMap<String, i64>,
         analyzer_input: &AnalyzerInput,
         printer: &mut smt::Printer<File>,
+        findings: &mut Vec<Finding>,
     ) -> Result<AnalyzerOutputStatus> {
         let mut result: AnalyzerOutputStatus = AnalyzerOutputStatus::NotUnderconstrainedLocal;
+        self.write_assumptions(printer)?;
         let mut variables: HashSet<String> = HashSet::new();
         for variable in printer.vars.keys() {
             variables.insert(variable.clone());
         }
+        let outputs = self.unique_variables(&variables)?;
 
         let mut max_iterations: u128 = 1;
 
@@ -1520,38 +3218,68 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         match analyzer_input.verification_method {
             // For specific public input, directly write assertions for each variable.
             VerificationMethod::Specific => {
-                for var in instance_cols_string {
-                    // Declare the variables in the SMT formula.
-                    smt::write_var(printer, var.0.to_owned());
-                    // Write an assertion that each veriables equals the given value.
-                    smt::write_assert(
-                        printer,
-                        var.0.clone(),
-                        (*var.1).to_string(),
-                        NodeType::Instance,
-                        Operation::Equal,
-                    );
-                }
+                Self::write_instance_values(printer, instance_cols_string);
             }
             // For random verification, set the number of iterations as specified.
             VerificationMethod::Random => {
                 max_iterations = analyzer_input.verification_input.iterations;
             }
+            // For global verification, a single query covers every public input.
+            VerificationMethod::Global => {}
         }
-        let model = Self::solve_and_get_model(smt_file_path.clone(), &variables)
+        let model = Self::solve_and_get_model(session, &variables)
             .context("Failed to solve and get model!")?;
         // With uninterpreted function, the model might be invalid due to the lookup constraints. We will ignore these models.
         let mut valid_model_lookeded_up = false;
+        if let Some(result) = Self::inconclusive(&model) {
+            return Ok(result);
+        }
         if matches!(model.sat, Satisfiability::Unsatisfiable) {
+            findings.push(Finding::Overconstrained {
+                conflicting_constraints: Self::conflicting_constraints(session, printer),
+            });
             result = AnalyzerOutputStatus::Overconstrained;
             return Ok(result); // We can just break here.
         }
+        // An output that occurs in no constraint can take any value for every public input.
+        let free_outputs: BTreeSet<&String> = outputs
+            .iter()
+            .flatten()
+            .filter(|var| !variables.contains(*var))
+            .collect();
+        if !free_outputs.is_empty() {
+            findings.extend(free_outputs.into_iter().map(|cell| Finding::FreeCell {
+                cell: self.locate_cell(cell),
+            }));
+            return Ok(AnalyzerOutputStatus::Underconstrained);
+        }
+        if matches!(&outputs, Some(outputs) if outputs.is_empty()) {
+            info!("Every output cell is a hint or is fixed by the public input!");
+            return Ok(AnalyzerOutputStatus::NotUnderconstrained);
+        }
+        if matches!(
+            analyzer_input.verification_method,
+            VerificationMethod::Global
+        ) {
+            return self.global_uniqueness_assertion(
+                session,
+                instance_cols_string,
+                analyzer_input,
+                printer,
+                findings,
+                &variables,
+                outputs.as_ref(),
+            );
+        }
         let mut uc_lookup_dependency_fp = false;
         let mut uc_lookup_dependency: bool = false;
         for i in 1..=max_iterations {
             // Attempt to solve the SMT problem and obtain a model.
-            let model = Self::solve_and_get_model(smt_file_path.clone(), &variables)
+            let model = Self::solve_and_get_model(session, &variables)
                 .context("Failed to solve and get model!")?;
+            if let Some(result) = Self::inconclusive(&model) {
+                return Ok(result);
+            }
             if matches!(model.sat, Satisfiability::Unsatisfiable) {
                 result = AnalyzerOutputStatus::NotUnderconstrained;
                 return Ok(result); // We can just break here.
@@ -1567,6 +3295,10 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             // if using uninterpreted function, we need to check if the model is valid by performing the lookup.
             else {
                 uc_lookup_dependency = false;
+                // Lookups into symbolic tables are always inlined, if there is no other lookup there is nothing to check.
+                if self.lookup_mappings.is_empty() {
+                    valid_model_lookeded_up = true;
+                }
                 // Lookup search to make sure all values in the model are valid.
                 for index in 0..self.lookup_mappings.len() {
                     // Perform the lookup
@@ -1581,7 +3313,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
             }
             // If the model is not valid, we ignore it and continue to the next iteration.
             if valid_model_lookeded_up {
-                println!("Model {} is valid!", i);
+                info!("Model {} is valid!", i);
                 info!("Model {} to be checked:", i);
                 for r in &model.result {
                     info!("{} : {}", r.1.name, r.1.value.element)
@@ -1595,62 +3327,26 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 //      3. add these rules to the current solver and,
                 //      4. find a model that satisfies these rules
 
-                let mut same_assignments = vec![];
-                let mut diff_assignments = vec![];
-                for var in variables.iter() {
-                    // The second condition is needed because the following constraints would've been added already to the solver in the beginning.
-                    // It is not strictly necessary, but there is no point in adding redundant constraints to the solver.
-                    if instance_cols_string.contains_key(var)
-                        && !matches!(
-                            analyzer_input.verification_method,
-                            VerificationMethod::Specific
-                        )
-                    {
-                        // 1. Fix the public input
-                        let result_from_model = &model.result[var];
-                        let sa = smt::get_assert(
-                            printer,
-                            result_from_model.name.clone(),
-                            result_from_model.value.element.clone(),
-                            NodeType::Instance,
-                            Operation::Equal,
-                        )
-                        .context("Failled to generate assert!")?;
-                        same_assignments.push(sa);
-                    } else {
-                        //2. Change the other vars
-                        let result_from_model = &model.result[var];
-                        let sa = smt::get_assert(
-                            printer,
-                            result_from_model.name.clone(),
-                            result_from_model.value.element.clone(),
-                            NodeType::Instance,
-                            Operation::NotEqual,
-                        )
-                        .context("Failled to generate assert!")?;
-                        diff_assignments.push(sa);
-                    }
-                }
-
-                let mut same_str = "".to_owned();
-                for var in same_assignments.iter() {
-                    same_str.push_str(var);
-                }
-                let mut diff_str = "".to_owned();
-                for var in diff_assignments.iter() {
-                    diff_str.push_str(var);
-                }
-
-                // 3. add these rules to the current solver,
-                let or_diff_assignments = smt::get_or(printer, diff_str);
-                same_str.push_str(&or_diff_assignments);
-                let and_all = smt::get_and(printer, same_str);
-                smt::write_assert_bool(printer, and_all, Operation::And);
+                Self::write_other_witness_assertion(
+                    printer,
+                    &variables,
+                    outputs.as_ref(),
+                    instance_cols_string,
+                    &model,
+                    matches!(
+                        analyzer_input.verification_method,
+                        VerificationMethod::Specific
+                    ),
+                )?;
 
                 // 4. find a model that satisfies these rules
                 let model_with_constraint =
-                    Self::solve_and_get_model(smt_file_path.clone(), &variables)
+                    Self::solve_and_get_model(session, &variables)
                         .context("Failed to solve and get model!")?;
+                // Without an answer, we can neither report the circuit nor rule out this public input.
+                if let Some(result) = Self::inconclusive(&model_with_constraint) {
+                    return Ok(result);
+                }
 
                 // If using uninterpreted function, we need to check if the model is valid by performing the lookup.
                 if matches!(analyzer_input.lookup_method, LookupMethod::Uninterpreted) {
@@ -1690,6 +3386,24 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                     for r in &model_with_constraint.result {
                         info!("{} : {}", r.1.name, r.1.value.element)
                     }
+                    let (differing_cells, equivalent_model) = self
+                        .minimize_differing_cells(
+                            session,
+                            printer,
+                            &variables,
+                            &model,
+                            model_with_constraint,
+                            &analyzer_input.lookup_method,
+                        )
+                        .context("Failed to minimize the differing cells!")?;
+                    findings.push(Finding::Underconstrained {
+                        model: Self::witness_model(&model),
+                        equivalent_model: Self::witness_model(&equivalent_model),
+                        differing_cells: differing_cells
+                            .iter()
+                            .map(|cell| self.locate_cell(cell))
+                            .collect(),
+                    });
                     result = AnalyzerOutputStatus::Underconstrained;
                     return Ok(result);
                 } else {
@@ -1726,57 +3440,482 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         }
         Ok(result)
     }
-    /// Generates a copy path for the SMT file.
+    /// Asserts that another witness exists for the public input of `model`.
+    ///
+    /// The instance cells are fixed to their value in `model`, unless `instances_fixed` tells that the public input
+    /// was asserted already, and at least one of the other variables (of the `outputs`, if any) has to differ from
+    /// `model`. Challenges are fixed to the same random value in both models.
+    fn write_other_witness_assertion(
+        printer: &mut smt::Printer<File>,
+        variables: &HashSet<String>,
+        outputs: Option<&HashSet<String>>,
+        instance_cols_string: &HashMap<String, i64>,
+        model: &ModelResult,
+        instances_fixed: bool,
+    ) -> Result<()> {
+        let mut same_assignments = vec![];
+        let mut diff_assignments = vec![];
+        for var in variables.iter() {
+            // Challenges are fixed to the same random value in both models.
+            if Self::is_challenge(var) {
+                continue;
+            }
+            // The second condition is needed because the following constraints would've been added already to the solver in the beginning.
+            // It is not strictly necessary, but there is no point in adding redundant constraints to the solver.
+            if instance_cols_string.contains_key(var) && !instances_fixed {
+                // 1. Fix the public input
+                let result_from_model = &model.result[var];
+                let sa = smt::get_assert(
+                    printer,
+                    result_from_model.name.clone(),
+                    result_from_model.value.element.clone(),
+                    NodeType::Instance,
+                    Operation::Equal,
+                )
+                .context("Failled to generate assert!")?;
+                same_assignments.push(sa);
+            } else if outputs.map_or(true, |outputs| outputs.contains(var)) {
+                //2. Change the other vars
+                let result_from_model = &model.result[var];
+                let sa = smt::get_assert(
+                    printer,
+                    result_from_model.name.clone(),
+                    result_from_model.value.element.clone(),
+                    NodeType::Instance,
+                    Operation::NotEqual,
+                )
+                .context("Failled to generate assert!")?;
+                diff_assignments.push(sa);
+            }
+        }
+
+        let mut same_str = "".to_owned();
+        for var in same_assignments.iter() {
+            same_str.push_str(var);
+        }
+        let mut diff_str = "".to_owned();
+        for var in diff_assignments.iter() {
+            diff_str.push_str(var);
+        }
+
+        // 3. add these rules to the current solver,
+        let or_diff_assignments = smt::get_or(printer, diff_str);
+        same_str.push_str(&or_diff_assignments);
+        let and_all = smt::get_and(printer, same_str);
+        smt::write_assert_bool(printer, and_all, Operation::And);
+
+        Ok(())
+    }
+    /// Checks whether any public input admits two witnesses, with a single query over two copies of the constraints.
     ///
-    /// This function takes the original SMT file path as input and generates a copy path
-    /// for the SMT file. The copy path is constructed by appending "_temp.smt2" to the
-    /// original file's stem (i.e., file name without extension), and placing it in the
-    /// "src/output/" directory. The function then creates a copy of the original file
-    /// at the generated copy path.
+    /// The second copy is written in a `(push)`/`(pop)` scope from the commands of the SMT file, with every variable
+    /// renamed except the instance cells and the challenges, and some advice cell (some output, if any) has to differ
+    /// between both copies.
+    /// If this is unsatisfiable, the circuit is not under-constrained for any public input. Otherwise the model gives
+    /// the public input and both witnesses, and the cells that differ are minimized like for the other verification
+    /// methods, with the public input fixed.
+    fn global_uniqueness_assertion(
+        &self,
+        session: &mut SmtSession,
+        instance_cols_string: &HashMap<String, i64>,
+        analyzer_input: &AnalyzerInput,
+        printer: &mut smt::Printer<File>,
+        findings: &mut Vec<Finding>,
+        variables: &HashSet<String>,
+        outputs: Option<&HashSet<String>>,
+    ) -> Result<AnalyzerOutputStatus> {
+        const SECOND_COPY_SUFFIX: &str = "!2";
+        let renamed: HashSet<String> = variables
+            .iter()
+            .filter(|var| !var.starts_with("I-") && !Self::is_challenge(var))
+            .cloned()
+            .collect();
+        let mut differences = "".to_owned();
+        let differs = |var: &&String| match outputs {
+            Some(outputs) => outputs.contains(*var),
+            None => var.starts_with("A-"),
+        };
+        for var in renamed.iter().filter(differs) {
+            differences.push_str(&format!("(not (= {} {}{}))", var, var, SECOND_COPY_SUFFIX));
+        }
+        // Without advice cells, the witness is the public input.
+        if differences.is_empty() {
+            return Ok(AnalyzerOutputStatus::NotUnderconstrained);
+        }
+
+        let commands = session.commands()?;
+        // The mappings of the shuffles are part of the witness, so each copy chooses its own permutations.
+        let mut copied = renamed.clone();
+        copied.extend(
+            commands
+                .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+                .filter(|token| token.starts_with("M-"))
+                .map(str::to_owned),
+        );
+        smt::write_push(printer, 1);
+        printer.write_renamed_copy(&commands, &copied, SECOND_COPY_SUFFIX);
+        smt::write_assert_bool(printer, differences, Operation::Or);
+        let mut both_copies = variables.clone();
+        both_copies.extend(
+            renamed
+                .iter()
+                .map(|var| format!("{}{}", var, SECOND_COPY_SUFFIX)),
+        );
+        let model = Self::solve_and_get_model(session, &both_copies)
+            .context("Failed to solve and get model!")?;
+        smt::write_pop(printer, 1);
+        printer
+            .vars
+            .retain(|var, _| !var.ends_with(SECOND_COPY_SUFFIX));
+        printer
+            .named_assertions
+            .retain(|name, _| !name.ends_with(SECOND_COPY_SUFFIX));
+        if let Some(result) = Self::inconclusive(&model) {
+            return Ok(result);
+        }
+        if matches!(model.sat, Satisfiability::Unsatisfiable) {
+            return Ok(AnalyzerOutputStatus::NotUnderconstrained);
+        }
+
+        // Split the model into the witnesses of both copies, which share the instance cells and the challenges.
+        let mut first = HashMap::new();
+        let mut second = HashMap::new();
+        for (name, variable) in model.result {
+            match name.strip_suffix(SECOND_COPY_SUFFIX) {
+                Some(original) => {
+                    second.insert(
+                        original.to_owned(),
+                        Variable {
+                            name: original.to_owned(),
+                            value: variable.value,
+                        },
+                    );
+                }
+                None => {
+                    if !renamed.contains(&name) {
+                        second.insert(name.clone(), variable.clone());
+                    }
+                    first.insert(name, variable);
+                }
+            }
+        }
+        let model = ModelResult {
+            sat: Satisfiability::Satisfiable,
+            result: first,
+        };
+        let second_model = ModelResult {
+            sat: Satisfiability::Satisfiable,
+            result: second,
+        };
+        // With uninterpreted functions, both witnesses are only valid if they satisfy the lookups.
+        if matches!(analyzer_input.lookup_method, LookupMethod::Uninterpreted) {
+            for witness in [&model, &second_model] {
+                for index in 0..self.lookup_mappings.len() {
+                    let lookup_sucessful = self
+                        .lookup(witness, index)
+                        .context("Failed to perform lookup")?;
+                    if !lookup_sucessful {
+                        info!("Lookup unsuccessful! Probably a false positive!");
+                        return Ok(
+                            AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups,
+                        );
+                    }
+                }
+            }
+        }
+        info!("Two witnesses for the same public input:");
+        for r in model.result.values().chain(second_model.result.values()) {
+            info!("{} : {}", r.name, r.value.element)
+        }
+
+        smt::write_push(printer, 1);
+        Self::write_other_witness_assertion(
+            printer,
+            variables,
+            outputs,
+            instance_cols_string,
+            &model,
+            false,
+        )?;
+        let (differing_cells, equivalent_model) = self
+            .minimize_differing_cells(
+                session,
+                printer,
+                variables,
+                &model,
+                second_model,
+                &analyzer_input.lookup_method,
+            )
+            .context("Failed to minimize the differing cells!")?;
+        smt::write_pop(printer, 1);
+        findings.push(Finding::Underconstrained {
+            model: Self::witness_model(&model),
+            equivalent_model: Self::witness_model(&equivalent_model),
+            differing_cells: differing_cells
+                .iter()
+                .map(|cell| self.locate_cell(cell))
+                .collect(),
+        });
+        Ok(AnalyzerOutputStatus::Underconstrained)
+    }
+    /// Shrinks the advice cells that differ between `model` and `equivalent_model` to a minimal set.
     ///
-    pub fn generate_copy_path(smt_file_path: String) -> Result<String> {
-        let smt_path_clone = smt_file_path.clone();
-        let smt_path_obj = Path::new(&smt_path_clone);
-        let smt_file_stem = smt_path_obj.file_stem().unwrap();
-        let smt_file_copy_path = format!(
-            "{}{}{}",
-            "src/output/",
-            smt_file_stem.to_str().unwrap(),
-            "_temp.smt2"
+    /// This runs in the scope where `equivalent_model` was found, i.e. with the public input fixed and some variable
+    /// different from `model`. The advice cells that are equal in both models are pinned to their value in `model`,
+    /// then the differing cells are pinned one at a time in a `(push)`/`(pop)` scope. If the query stays satisfiable,
+    /// the cell is pinned for good and the set shrinks to the cells that differ in the new model, otherwise the cell
+    /// has to differ. An `unknown` answer stops the search with the set found so far.
+    /// Returns the (sorted) minimal set and a model that differs from `model` in these advice cells only.
+    fn minimize_differing_cells(
+        &self,
+        session: &mut SmtSession,
+        printer: &mut smt::Printer<File>,
+        variables: &HashSet<String>,
+        model: &ModelResult,
+        equivalent_model: ModelResult,
+        lookup_method: &LookupMethod,
+    ) -> Result<(Vec<String>, ModelResult)> {
+        let advice_cells: Vec<&String> = variables
+            .iter()
+            .filter(|var| var.starts_with("A-"))
+            .collect();
+        let differing = |other: &ModelResult| -> Vec<String> {
+            let mut cells: Vec<String> = advice_cells
+                .iter()
+                .filter(|var| {
+                    model.result[**var].value.element != other.result[**var].value.element
+                })
+                .map(|var| (*var).clone())
+                .collect();
+            cells.sort();
+            cells
+        };
+        let mut equivalent_model = equivalent_model;
+        let mut differing_cells = differing(&equivalent_model);
+
+        smt::write_push(printer, 1);
+        for var in &advice_cells {
+            if !differing_cells.contains(*var) {
+                Self::pin_to_model(printer, var, model)?;
+            }
+        }
+        for cell in differing_cells.clone() {
+            // Already pinned by a previous model.
+            if !differing_cells.contains(&cell) {
+                continue;
+            }
+            smt::write_push(printer, 1);
+            Self::pin_to_model(printer, &cell, model)?;
+            let candidate = Self::solve_and_get_model(session, variables)
+                .context("Failed to solve and get model!")?;
+            smt::write_pop(printer, 1);
+            match candidate.sat {
+                Satisfiability::Satisfiable => {}
+                Satisfiability::Unsatisfiable => continue,
+                Satisfiability::Unknown(_) => break,
+            }
+            // With uninterpreted functions, the new model is only valid if it satisfies the lookups.
+            if matches!(lookup_method, LookupMethod::Uninterpreted) {
+                let mut lookups_successful = true;
+                for index in 0..self.lookup_mappings.len() {
+                    lookups_successful &= self
+                        .lookup(&candidate, index)
+                        .context("Failed to perform lookup")?;
+                }
+                if !lookups_successful {
+                    continue;
+                }
+            }
+            let remaining = differing(&candidate);
+            for var in differing_cells.iter() {
+                if !remaining.contains(var) {
+                    Self::pin_to_model(printer, var, model)?;
+                }
+            }
+            differing_cells = remaining;
+            equivalent_model = candidate;
+        }
+        smt::write_pop(printer, 1);
+        Ok((differing_cells, equivalent_model))
+    }
+
+    /// Asserts that `var` takes its value in `model`.
+    fn pin_to_model(
+        printer: &mut smt::Printer<File>,
+        var: &str,
+        model: &ModelResult,
+    ) -> Result<()> {
+        smt::write_assert(
+            printer,
+            var.to_owned(),
+            Self::model_value(model, var)?,
+            NodeType::Advice,
+            Operation::Equal,
         );
-        fs::copy(smt_file_path, smt_file_copy_path.clone()).context("Failed to copy file!")?;
-        Ok(smt_file_copy_path)
+        Ok(())
+    }
+
+    /// Returns the value of `var` in `model`, which the solver may have left out.
+    fn model_value(model: &ModelResult, var: &str) -> Result<String> {
+        model
+            .result
+            .get(var)
+            .map(|variable| variable.value.element.clone())
+            .with_context(|| format!("The model has no value for {}!", var))
+    }
+
+    /// Finds the region, column annotation and row of an advice cell variable (`A-col-row`).
+    fn locate_cell(&self, cell: &str) -> CellLocation {
+        let mut parts = cell
+            .split('-')
+            .skip(1)
+            .map(|part| part.parse().unwrap_or_default());
+        let index = parts.next().unwrap_or_default();
+        let row = parts.next().unwrap_or_default();
+        let mut location = CellLocation {
+            cell: cell.to_owned(),
+            region_name: None,
+            column: ColumnLocation {
+                column_type: ColumnType::Advice,
+                index,
+            },
+            annotation: None,
+            row,
+        };
+        for region in self.regions.iter() {
+            #[cfg(feature = "use_zcash_halo2_proofs")]
+            let mut cells = region.cells.iter().map(|(column, row)| (column, *row));
+            #[cfg(not(feature = "use_zcash_halo2_proofs"))]
+            let mut cells = region.cells.keys().map(|(column, row)| (column, *row));
+            let column = cells.find_map(|(column, cell_row)| {
+                (matches!(column.column_type(), Any::Advice { .. })
+                    && column.index() == index
+                    && cell_row == row)
+                    .then_some(column)
+            });
+            if let Some(column) = column {
+                location.region_name = Some(region.name.clone());
+                location.annotation = Self::column_annotation(region, column);
+                break;
+            }
+        }
+        location
+    }
+
+    #[cfg(any(
+        feature = "use_pse_halo2_proofs",
+        feature = "use_axiom_halo2_proofs",
+        feature = "use_scroll_halo2_proofs"
+    ))]
+    fn column_annotation(region: &Region, column: &Column<Any>) -> Option<String> {
+        region
+            .annotations
+            .get(&ColumnMetadata::from(*column))
+            .cloned()
+    }
+
+    // These versions of halo2 do not record column annotations.
+    #[cfg(any(
+        feature = "use_zcash_halo2_proofs",
+        feature = "use_pse_v1_halo2_proofs"
+    ))]
+    fn column_annotation(_region: &Region, _column: &Column<Any>) -> Option<String> {
+        None
+    }
+
+    /// Maps the unsat core of the last query of `session` back to the constraints of the circuit.
+    ///
+    /// Only the assertions named by `printer` can be part of the core, the other ones (e.g. the range of the
+    /// variables) are always assumed. Returns no constraint if `AnalyzerConfig::unsat_cores` is not set or the solver
+    /// cannot report an unsat core.
+    fn conflicting_constraints(
+        session: &mut SmtSession,
+        printer: &smt::Printer<File>,
+    ) -> Vec<ConstraintOrigin> {
+        // Unsat cores are off or the solver does not support them.
+        if printer.named_assertions.is_empty() {
+            return vec![];
+        }
+        let names = match session.unsat_core() {
+            Ok(names) => names,
+            Err(err) => {
+                info!("Failed to retrieve the unsat core: {:?}", err);
+                return vec![];
+            }
+        };
+        let mut constraints: Vec<ConstraintOrigin> = vec![];
+        for name in names {
+            if let Some(origin) = printer.named_assertions.get(&name) {
+                // A constraint may be made of several assertions.
+                if !constraints.contains(origin) {
+                    constraints.push(origin.clone());
+                }
+            }
+        }
+        constraints
     }
-    // Solves the SMT formula in the specified file and retrieves the model result.
+
+    /// Solves the SMT formula written so far and retrieves the model result.
     ///
-    /// This function solves the SMT formula in the given `smt_file_path` by executing the CVC5 solver.
-    /// It appends the necessary commands to the SMT file for checking satisfiability and retrieving values
-    /// for the specified variables. The function then runs the CVC5 solver and captures its output.
+    /// The commands written to the SMT file since the previous call are forwarded to the solver of `session`,
+    /// which then checks satisfiability and retrieves the values of the specified variables.
     /// The output is parsed to extract the model result, which is returned as a `ModelResult`.
     ///
     pub fn solve_and_get_model(
-        smt_file_path: String,
+        session: &mut SmtSession,
         variables: &HashSet<String>,
     ) -> Result<ModelResult> {
-        let smt_file_copy_path =
-            Self::generate_copy_path(smt_file_path).context("Failed to generate copy path!")?;
-        let mut smt_file_copy = OpenOptions::new()
-            .append(true)
-            .open(smt_file_copy_path.clone())
-            .expect("cannot open file");
-        let mut copy_printer = Printer::new(&mut smt_file_copy);
-
-        // Add (check-sat) (get-value var) ... here.
-        smt::write_end(&mut copy_printer);
-        for var in variables.iter() {
-            smt::write_get_value(&mut copy_printer, var.clone());
+        session.solve(variables)
+    }
+
+    /// Adds to `model` the value of every cell that only occurs in the SMT query through the head of its copy cycle.
+    ///
+    /// The analysis declares one variable per copy cycle, so the model of a finding only holds the cycle heads.
+    pub fn complete_witness(&self, model: &WitnessModel) -> WitnessModel {
+        let mut witness = model.clone();
+        for (cell, head) in &self.cell_to_cycle_head {
+            if let Some(value) = model.get(head) {
+                witness.entry(cell.clone()).or_insert_with(|| value.clone());
+            }
+        }
+        witness
+    }
+
+    /// Returns the counterexamples of the under-constrained findings of `analyzer_output`.
+    pub fn counterexamples(&self, analyzer_output: &AnalyzerOutput) -> Vec<Counterexample> {
+        analyzer_output
+            .findings
+            .iter()
+            .filter_map(|finding| match finding {
+                Finding::Underconstrained {
+                    model,
+                    equivalent_model,
+                    ..
+                } => Some(Counterexample {
+                    witness: self.complete_witness(model),
+                    equivalent_witness: self.complete_witness(equivalent_model),
+                }),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Returns the `Inconclusive` status if the solver could not decide the query of `model`.
+    fn inconclusive(model: &ModelResult) -> Option<AnalyzerOutputStatus> {
+        match &model.sat {
+            Satisfiability::Unknown(reason) => Some(AnalyzerOutputStatus::Inconclusive {
+                reason: reason.clone(),
+            }),
+            _ => None,
         }
-        let output = Command::new("cvc5").arg(smt_file_copy_path).output();
-        let term = output.unwrap();
-        let output_string = String::from_utf8_lossy(&term.stdout);
+    }
 
-        smt_parser::extract_model_response(output_string.to_string())
-            .context("Failed to parse smt result!")
+    fn witness_model(model: &ModelResult) -> WitnessModel {
+        model
+            .result
+            .values()
+            .map(|var| (var.name.clone(), var.value.element.clone()))
+            .collect()
     }
 
     fn lookup(&self, model_with_constraint: &ModelResult, index: usize) -> Option<bool> {
@@ -1797,7 +3936,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         'outer: for (_, row) in self.fixed_converted.iter().enumerate() {
             for &(column_index, variable) in &variables {
                 if let Some(cell) = row.get(column_index) {
-                    let t = format!("{:?}", cell);
+                    let t = cell.to_string();
 
                     if t != variable.value.element {
                         continue 'outer;
@@ -1812,7 +3951,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         Some(false)
     }
 
-    fn have_same_rows(matrix1: Vec<Vec<u64>>, matrix2: Vec<Vec<u64>>) -> bool {
+    fn have_same_rows(matrix1: Vec<Vec<BigUint>>, matrix2: Vec<Vec<BigUint>>) -> bool {
         if matrix1.len() != matrix2.len() {
             return false;
         }
@@ -1820,8 +3959,8 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         let num_rows = matrix1.len();
 
         // Transform matrices into sets of column tuples
-        let mut set1: HashSet<Vec<u64>> = HashSet::new();
-        let mut set2: HashSet<Vec<u64>> = HashSet::new();
+        let mut set1: HashSet<Vec<BigUint>> = HashSet::new();
+        let mut set2: HashSet<Vec<BigUint>> = HashSet::new();
     
         for col_index in 0..num_columns {
             let mut col_tuple1 = Vec::with_capacity(num_rows);
@@ -1841,7 +3980,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         set1 == set2
     }
 
-    fn match_equivalent_lookup_tables(&self, new_lookup_table: &[Vec<u64>]) -> (bool, usize) {
+    fn match_equivalent_lookup_tables(&self, new_lookup_table: &[Vec<BigUint>]) -> (bool, usize) {
         for (index, existing_table) in self.lookup_tables.iter().enumerate() {
             let result =
                 Self::have_same_rows(existing_table.fixed.clone(), new_lookup_table.to_vec());
@@ -1854,7 +3993,7 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
         (false, 0) // No match found, return false with a default index
     }
 
-    fn extract_lookup_columns(&self, col_indices: &[usize]) -> Vec<Vec<u64>> {
+    fn extract_lookup_columns(&self, col_indices: &[usize]) -> Vec<Vec<BigUint>> {
         col_indices
             .iter()
             .filter_map(|&index| self.fixed_converted.get(index).cloned())
@@ -1871,14 +4010,14 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
     /// - `UnusedColumns`: Analyzes and identifies unused columns in the circuit.
     /// - `UnderconstrainedCircuit`: Analyzes the circuit for underconstrained properties by
     ///   retrieving user input for specific instance columns and conducting analysis.
+    /// - `CellDeterminism`: Checks which advice cells are determined by the public input, with the same user input
+    ///   as `UnderconstrainedCircuit`.
+    /// - `Specification`: Checks that the constraints imply the specifications of the circuit, which are read from
+    ///   stdin if none was given, with the same user input as `UnderconstrainedCircuit`.
     ///
     /// The function performs the analysis and updates the internal state accordingly.
     ///
-    pub fn dispatch_analysis(
-        &mut self,
-        analyzer_type: AnalyzerType,
-        prime: &str,
-    ) -> Result<AnalyzerOutput> {
+    pub fn dispatch_analysis(&mut self, analyzer_type: AnalyzerType) -> Result<AnalyzerOutput> {
         match analyzer_type {
             AnalyzerType::UnusedGates => self.analyze_unused_custom_gates(),
             AnalyzerType::UnconstrainedCells => self.analyze_unconstrained_cells(),
@@ -1887,7 +4026,26 @@ impl<'b, F: AnalyzableField> Analyzer<F> {
                 let analyzer_input: AnalyzerInput =
                     retrieve_user_input_for_underconstrained(&self.instace_cells, &self.cs)
                         .context("Failed to retrieve user input!")?;
-                self.analyze_underconstrained(analyzer_input, prime)
+                self.analyze_underconstrained(analyzer_input)
+            }
+            AnalyzerType::CellDeterminism => {
+                let analyzer_input: AnalyzerInput =
+                    retrieve_user_input_for_underconstrained(&self.instace_cells, &self.cs)
+                        .context("Failed to retrieve user input!")?;
+                self.analyze_cell_determinism(analyzer_input, CellScope::All)
+            }
+            AnalyzerType::Specification => {
+                if self.specifications.is_empty() {
+                    self.specifications = retrieve_user_input_for_specification()
+                        .context("Failed to retrieve the specifications!")?
+                        .into_iter()
+                        .map(Specification::Equation)
+                        .collect();
+                }
+                let analyzer_input: AnalyzerInput =
+                    retrieve_user_input_for_underconstrained(&self.instace_cells, &self.cs)
+                        .context("Failed to retrieve user input!")?;
+                self.analyze_specification(analyzer_input)
             }
         }
     }
@@ -0,0 +1,111 @@
+use std::marker::PhantomData;
+
+use super::analyzable::AnalyzableField;
+use super::analyzer::{Analyzer, Specification};
+use super::halo2_proofs_libs::*;
+use crate::io::analyzer_io_type::{AnalyzerConfig, Assumption, CellSelector, SpecEquation};
+
+/// Builds an `Analyzer` together with the options of its analyses.
+///
+/// Chip authors use it to mark the advice cells of their gadgets that are intentional hints, by region name or by
+/// column annotation (the annotations are only recorded by the pse, axiom and scroll versions of halo2):
+///
+/// ```ignore
+/// let mut analyzer = Analyzer::builder(&circuit, k)
+///     .hint_annotation("inv")
+///     .output_cell(CellSelector::Region("Is Zero".to_owned()))
+///     .assume("I-0-0 < 2^64".parse()?)
+///     .build()?;
+/// ```
+///
+/// The relations the circuit is expected to enforce are given the same way, for the specification analysis:
+///
+/// ```ignore
+/// let mut analyzer = Analyzer::builder(&circuit, k)
+///     .spec("I-0-0 = A-0-0 * A-1-0 + A-2-0".parse()?)
+///     .build()?;
+/// analyzer.analyze_specification(analyzer_input)?;
+/// ```
+pub struct AnalyzerBuilder<'a, F: AnalyzableField, C: Circuit<F>> {
+    circuit: &'a C,
+    k: u32,
+    config: AnalyzerConfig,
+    output_cells: Vec<CellSelector>,
+    hint_cells: Vec<CellSelector>,
+    assumptions: Vec<Assumption>,
+    specifications: Vec<Specification<F>>,
+    _field: PhantomData<F>,
+}
+
+impl<'a, F: AnalyzableField, C: Circuit<F>> AnalyzerBuilder<'a, F, C> {
+    pub fn new(circuit: &'a C, k: u32) -> Self {
+        AnalyzerBuilder {
+            circuit,
+            k,
+            config: AnalyzerConfig::default(),
+            output_cells: Vec::new(),
+            hint_cells: Vec::new(),
+            assumptions: Vec::new(),
+            specifications: Vec::new(),
+            _field: PhantomData,
+        }
+    }
+
+    /// Sets the options of the under-constrained analysis.
+    pub fn config(mut self, config: AnalyzerConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Adds advice cells that must be unique, see `Analyzer::output_cells`.
+    pub fn output_cell(mut self, selector: CellSelector) -> Self {
+        self.output_cells.push(selector);
+        self
+    }
+
+    /// Adds advice cells that are intentional hints, see `Analyzer::hint_cells`.
+    pub fn hint(mut self, selector: CellSelector) -> Self {
+        self.hint_cells.push(selector);
+        self
+    }
+
+    /// Marks the advice cells assigned in the regions with this name as intentional hints.
+    pub fn hint_region(self, name: impl Into<String>) -> Self {
+        self.hint(CellSelector::Region(name.into()))
+    }
+
+    /// Marks the advice cells of the columns annotated with this name as intentional hints.
+    pub fn hint_annotation(self, annotation: impl Into<String>) -> Self {
+        self.hint(CellSelector::Annotation(annotation.into()))
+    }
+
+    /// Adds a precondition under which the public input must determine the witness, see `Analyzer::assumptions`.
+    pub fn assume(mut self, assumption: Assumption) -> Self {
+        self.assumptions.push(assumption);
+        self
+    }
+
+    /// Adds a relation that the constraints must imply, see `Analyzer::specifications`.
+    pub fn spec(mut self, equation: SpecEquation) -> Self {
+        self.specifications.push(Specification::Equation(equation));
+        self
+    }
+
+    /// Adds the relation `lhs = rhs` between halo2 expressions queried relative to the absolute row `row`.
+    pub fn spec_expressions(mut self, lhs: Expression<F>, rhs: Expression<F>, row: usize) -> Self {
+        self.specifications
+            .push(Specification::Expressions { lhs, rhs, row });
+        self
+    }
+
+    /// Synthesizes the circuit and returns its analyzer.
+    pub fn build(self) -> Result<Analyzer<F>, Error> {
+        let mut analyzer = Analyzer::new(self.circuit, self.k)?;
+        analyzer.config = self.config;
+        analyzer.output_cells = self.output_cells;
+        analyzer.hint_cells = self.hint_cells;
+        analyzer.assumptions = self.assumptions;
+        analyzer.specifications = self.specifications;
+        Ok(analyzer)
+    }
+}
@@ -27,7 +27,7 @@ pub use pse_halo2_proofs::{
         Expression,
         Challenge,
         sealed,
-        Phase,FirstPhase,
+        Phase,FirstPhase,SecondPhase,ThirdPhase,
         permutation, Advice, Any, Assigned, Assignment, Circuit, Column, ConstraintSystem, Error,
         Fixed, FloorPlanner, Instance, Selector,
         sealed::SealedPhase,
@@ -73,7 +73,7 @@ pub use axiom_halo2_proofs::{
         Expression,
         Challenge,
         sealed,
-        Phase,FirstPhase,
+        Phase,FirstPhase,SecondPhase,ThirdPhase,
         permutation, Advice, Any, Assigned, Assignment, Circuit, Column, ConstraintSystem, Error,
         Fixed, FloorPlanner, Instance, Selector,
         sealed::SealedPhase,
@@ -97,7 +97,7 @@ pub use scroll_halo2_proofs::{
         Expression,
         Challenge,
         sealed,
-        Phase,FirstPhase,
+        Phase,FirstPhase,SecondPhase,ThirdPhase,
         permutation, Advice, Any, Assigned, Assignment, Circuit, Column, ConstraintSystem, Error,
         Fixed, FloorPlanner, Instance, Selector,
         sealed::SealedPhase,
@@ -1,4 +1,8 @@
 pub mod abstract_expr;
 pub mod analyzer;
 pub mod analyzable;
+pub mod builder;
 pub mod halo2_proofs_libs;
+pub mod registry;
+#[cfg(not(feature = "use_axiom_halo2_proofs"))]
+pub mod replay;
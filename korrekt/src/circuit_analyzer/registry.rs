@@ -0,0 +1,90 @@
+use anyhow::{anyhow, Result};
+use std::collections::BTreeMap;
+
+use super::{analyzable::AnalyzableField, analyzer::Analyzer, halo2_proofs_libs::*};
+
+/// Builds an `Analyzer` for a circuit laid out over 2^k rows.
+pub type CircuitConstructor<F> = fn(u32) -> Result<Analyzer<F>, Error>;
+
+/// Maps circuit names to the constructors of their analyzers.
+///
+/// The `korrekt` binary looks up the circuit given with `--circuit <name>` in this registry.
+/// Downstream crates can start from `CircuitRegistry::with_sample_circuits()` (or an empty registry),
+/// add their own circuits with `register_circuit!` or `CircuitRegistry::register`, and hand it to `korrekt::cli::run`.
+pub struct CircuitRegistry<F: AnalyzableField> {
+    circuits: BTreeMap<String, CircuitConstructor<F>>,
+}
+
+impl<F: AnalyzableField> Default for CircuitRegistry<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: AnalyzableField> CircuitRegistry<F> {
+    pub fn new() -> Self {
+        CircuitRegistry {
+            circuits: BTreeMap::new(),
+        }
+    }
+
+    /// Registers `constructor` under `name`, replacing any circuit previously registered under the same name.
+    pub fn register(&mut self, name: &str, constructor: CircuitConstructor<F>) -> &mut Self {
+        self.circuits.insert(name.to_owned(), constructor);
+        self
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.circuits.contains_key(name)
+    }
+
+    /// Names of the registered circuits, in alphabetical order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.circuits.keys().map(String::as_str)
+    }
+
+    /// Synthesizes the circuit registered under `name` and returns its analyzer.
+    pub fn build(&self, name: &str, k: u32) -> Result<Analyzer<F>> {
+        let constructor = self.circuits.get(name).ok_or_else(|| {
+            anyhow!(
+                "Unknown circuit \"{}\", the registered circuits are: {}",
+                name,
+                self.names().collect::<Vec<_>>().join(", ")
+            )
+        })?;
+        constructor(k).map_err(|e| anyhow!("Failed to synthesize circuit \"{}\": {:?}", name, e))
+    }
+}
+
+impl CircuitRegistry<Fr> {
+    /// Returns a registry holding every sample circuit of the enabled halo2 backend.
+    pub fn with_sample_circuits() -> Self {
+        let mut registry = Self::new();
+        #[cfg(feature = "use_zcash_halo2_proofs")]
+        crate::sample_circuits::zcash::register_circuits(&mut registry);
+        #[cfg(feature = "use_pse_halo2_proofs")]
+        crate::sample_circuits::pse::register_circuits(&mut registry);
+        #[cfg(feature = "use_axiom_halo2_proofs")]
+        crate::sample_circuits::axiom::register_circuits(&mut registry);
+        #[cfg(feature = "use_scroll_halo2_proofs")]
+        crate::sample_circuits::scroll::register_circuits(&mut registry);
+        #[cfg(feature = "use_pse_v1_halo2_proofs")]
+        crate::sample_circuits::pse_v1::register_circuits(&mut registry);
+        registry
+    }
+}
+
+/// Registers a circuit type under a name, using its `Default` instance as the circuit to analyze.
+///
+/// ```ignore
+/// let mut registry = CircuitRegistry::<Fr>::new();
+/// register_circuit!(registry, "my_circuit", MyCircuit<Fr>);
+/// ```
+#[macro_export]
+macro_rules! register_circuit {
+    ($registry:expr, $name:expr, $circuit:ty) => {
+        $registry.register($name, |k| {
+            $crate::circuit_analyzer::analyzer::Analyzer::new(&<$circuit>::default(), k)
+        })
+    };
+}
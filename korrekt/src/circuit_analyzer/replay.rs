@@ -0,0 +1,295 @@
+use anyhow::{anyhow, Context, Result};
+use num::{BigInt, BigUint, Integer};
+use std::{cell::RefCell, collections::HashMap, marker::PhantomData};
+
+#[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
+use ff::FromUniformBytes;
+
+use super::analyzable::{field_modulus, AnalyzableField};
+use super::halo2_proofs_libs::*;
+use crate::io::analyzer_io_type::WitnessModel;
+
+thread_local! {
+    /// The advice cells overridden while a circuit is synthesized by `replay_witness`, keyed by (column, row).
+    ///
+    /// A floor planner is a type, not a value, so this is how `ReplayFloorPlanner` gets the witness.
+    static REPLAYED_ADVICE: RefCell<HashMap<(usize, usize), BigUint>> = RefCell::new(HashMap::new());
+}
+
+/// Clears `REPLAYED_ADVICE` when dropped, so that a witness is not replayed into a later synthesis, even if the
+/// mock prover fails or panics.
+struct ReplayedAdviceGuard;
+
+impl Drop for ReplayedAdviceGuard {
+    fn drop(&mut self) {
+        REPLAYED_ADVICE.with(|replayed| replayed.borrow_mut().clear());
+    }
+}
+
+// The mock provers of the halo2 backends require different bounds on the field.
+#[cfg(feature = "use_pse_v1_halo2_proofs")]
+pub trait ReplayableField: AnalyzableField + FieldExt {}
+#[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
+pub trait ReplayableField: AnalyzableField + FromUniformBytes<64> + Ord {}
+
+#[cfg(feature = "use_pse_v1_halo2_proofs")]
+impl<F: AnalyzableField + FieldExt> ReplayableField for F {}
+#[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
+impl<F: AnalyzableField + FromUniformBytes<64> + Ord> ReplayableField for F {}
+
+/// Converts the canonical integer representative of a field element back to the element.
+fn biguint_to_field<F: Field>(value: &BigUint) -> F {
+    #[cfg(feature = "use_pse_v1_halo2_proofs")]
+    let (zero, one) = (F::zero(), F::one());
+    #[cfg(not(feature = "use_pse_v1_halo2_proofs"))]
+    let (zero, one) = (F::ZERO, F::ONE);
+    (0..value.bits()).rev().fold(zero, |acc, bit| {
+        let acc = acc.double();
+        if value.bit(bit) {
+            acc + one
+        } else {
+            acc
+        }
+    })
+}
+
+/// Parses a cell variable such as `A-1-4` into its column type, column index and row.
+fn parse_cell(name: &str) -> Option<(char, usize, usize)> {
+    let mut parts = name.split('-');
+    let column_type = parts.next()?.chars().next()?;
+    let column = parts.next()?.parse().ok()?;
+    let row = parts.next()?.parse().ok()?;
+    if parts.next().is_some() {
+        return None;
+    }
+    Some((column_type, column, row))
+}
+
+/// Forwards every assignment to `cs`, except for the advice cells of the replayed witness.
+struct ReplayAssignment<'a, CS> {
+    cs: &'a mut CS,
+    advice: &'a HashMap<(usize, usize), BigUint>,
+}
+
+impl<'a, F: Field, CS: Assignment<F>> Assignment<F> for ReplayAssignment<'a, CS> {
+    fn enter_region<NR, N>(&mut self, name: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.enter_region(name)
+    }
+
+    fn exit_region(&mut self) {
+        self.cs.exit_region()
+    }
+
+    fn enable_selector<A, AR>(
+        &mut self,
+        annotation: A,
+        selector: &Selector,
+        row: usize,
+    ) -> Result<(), Error>
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.enable_selector(annotation, selector, row)
+    }
+
+    fn query_instance(&self, column: Column<Instance>, row: usize) -> Result<Value<F>, Error> {
+        self.cs.query_instance(column, row)
+    }
+
+    fn assign_advice<V, VR, A, AR>(
+        &mut self,
+        annotation: A,
+        column: Column<Advice>,
+        row: usize,
+        to: V,
+    ) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        match self.advice.get(&(column.index(), row)) {
+            Some(value) => {
+                let value: F = biguint_to_field(value);
+                self.cs
+                    .assign_advice(annotation, column, row, || Value::known(value))
+            }
+            None => self.cs.assign_advice(annotation, column, row, to),
+        }
+    }
+
+    fn assign_fixed<V, VR, A, AR>(
+        &mut self,
+        annotation: A,
+        column: Column<Fixed>,
+        row: usize,
+        to: V,
+    ) -> Result<(), Error>
+    where
+        V: FnOnce() -> Value<VR>,
+        VR: Into<Assigned<F>>,
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.assign_fixed(annotation, column, row, to)
+    }
+
+    fn copy(
+        &mut self,
+        left_column: Column<Any>,
+        left_row: usize,
+        right_column: Column<Any>,
+        right_row: usize,
+    ) -> Result<(), Error> {
+        self.cs.copy(left_column, left_row, right_column, right_row)
+    }
+
+    fn fill_from_row(
+        &mut self,
+        column: Column<Fixed>,
+        row: usize,
+        to: Value<Assigned<F>>,
+    ) -> Result<(), Error> {
+        self.cs.fill_from_row(column, row, to)
+    }
+
+    fn push_namespace<NR, N>(&mut self, name: N)
+    where
+        NR: Into<String>,
+        N: FnOnce() -> NR,
+    {
+        self.cs.push_namespace(name)
+    }
+
+    fn pop_namespace(&mut self, gadget_name: Option<String>) {
+        self.cs.pop_namespace(gadget_name)
+    }
+
+    #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_scroll_halo2_proofs"))]
+    fn annotate_column<A, AR>(&mut self, annotation: A, column: Column<Any>)
+    where
+        A: FnOnce() -> AR,
+        AR: Into<String>,
+    {
+        self.cs.annotate_column(annotation, column)
+    }
+
+    #[cfg(any(feature = "use_pse_halo2_proofs", feature = "use_scroll_halo2_proofs"))]
+    fn get_challenge(&self, challenge: Challenge) -> Value<F> {
+        self.cs.get_challenge(challenge)
+    }
+
+    #[cfg(feature = "use_scroll_halo2_proofs")]
+    fn query_advice(&self, column: Column<Advice>, row: usize) -> Result<F, Error> {
+        self.cs.query_advice(column, row)
+    }
+
+    #[cfg(feature = "use_scroll_halo2_proofs")]
+    fn query_fixed(&self, column: Column<Fixed>, row: usize) -> Result<F, Error> {
+        self.cs.query_fixed(column, row)
+    }
+}
+
+/// Lays out the circuit with the floor planner `P`, overriding the advice cells of the replayed witness.
+pub struct ReplayFloorPlanner<P>(PhantomData<P>);
+
+impl<P: FloorPlanner> FloorPlanner for ReplayFloorPlanner<P> {
+    fn synthesize<F: Field, CS: Assignment<F>, C: Circuit<F>>(
+        cs: &mut CS,
+        circuit: &C,
+        config: C::Config,
+        constants: Vec<Column<Fixed>>,
+    ) -> Result<(), Error> {
+        let advice = REPLAYED_ADVICE.with(|advice| advice.borrow().clone());
+        let mut replay = ReplayAssignment {
+            cs,
+            advice: &advice,
+        };
+        P::synthesize(&mut replay, circuit, config, constants)
+    }
+}
+
+/// The circuit `C`, with the advice cells of the replayed witness assigned instead of its own.
+struct ReplayCircuit<'a, C>(&'a C);
+
+impl<'a, F: Field, C: Circuit<F>> Circuit<F> for ReplayCircuit<'a, C> {
+    type Config = C::Config;
+    type FloorPlanner = ReplayFloorPlanner<C::FloorPlanner>;
+
+    fn without_witnesses(&self) -> Self {
+        ReplayCircuit(self.0)
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        C::configure(meta)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        self.0.synthesize(config, layouter)
+    }
+}
+
+/// Runs `MockProver::verify` on `circuit` with the witness of a counterexample.
+///
+/// The advice cells of `witness` (`A-col-row`) replace the values assigned by the circuit, the other advice cells
+/// keep them, and the instance cells (`I-col-row`) are the public inputs. Complete the model of a finding with
+/// `Analyzer::complete_witness` first, so that every cell of a copy cycle is assigned.
+/// Returns an error listing the failures if the witness does not satisfy the circuit.
+pub fn replay_witness<F: ReplayableField, C: Circuit<F>>(
+    circuit: &C,
+    k: u32,
+    witness: &WitnessModel,
+) -> Result<()> {
+    let modulus = BigInt::from(field_modulus::<F>());
+    let mut advice = HashMap::new();
+    let mut instances: HashMap<usize, Vec<(usize, F)>> = HashMap::new();
+    for (name, value) in witness {
+        // Solvers may print field elements as signed integers.
+        let value = value
+            .parse::<BigInt>()
+            .with_context(|| format!("Invalid value of {} in the witness!", name))?
+            .mod_floor(&modulus)
+            .magnitude()
+            .clone();
+        match parse_cell(name) {
+            Some(('A', column, row)) => {
+                advice.insert((column, row), value);
+            }
+            Some(('I', column, row)) => instances
+                .entry(column)
+                .or_default()
+                .push((row, biguint_to_field(&value))),
+            // Challenges and auxiliary variables are not cells.
+            _ => {}
+        }
+    }
+
+    let mut cs = ConstraintSystem::<F>::default();
+    C::configure(&mut cs);
+    let instances: Vec<Vec<F>> = (0..cs.num_instance_columns())
+        .map(|column| {
+            let cells = instances.remove(&column).unwrap_or_default();
+            let rows = cells.iter().map(|(row, _)| row + 1).max().unwrap_or(0);
+            let mut values = vec![biguint_to_field(&BigUint::default()); rows];
+            for (row, value) in cells {
+                values[row] = value;
+            }
+            values
+        })
+        .collect();
+
+    REPLAYED_ADVICE.with(|replayed| *replayed.borrow_mut() = advice);
+    let guard = ReplayedAdviceGuard;
+    let prover = MockProver::run(k, &ReplayCircuit(circuit), instances);
+    drop(guard);
+    prover
+        .map_err(|err| anyhow!("Failed to run the mock prover: {:?}", err))?
+        .verify()
+        .map_err(|failures| anyhow!("The witness does not satisfy the circuit: {:?}", failures))
+}
@@ -0,0 +1,521 @@
+use anyhow::{anyhow, Context, Result};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+use env_logger::Env;
+use std::{collections::HashMap, ffi::OsString, fs::File, io, path::PathBuf, time::Duration};
+
+use crate::{
+    circuit_analyzer::{
+        analyzable::AnalyzableField,
+        analyzer::{Analyzer, Specification},
+        registry::CircuitRegistry,
+    },
+    io::{
+        analyzer_io::{output_result, retrieve_user_input_for_analyzer_type},
+        analyzer_io_type::{
+            AnalyzerConfig, AnalyzerInput, AnalyzerOutput, AnalyzerType, Assumption, CellScope,
+            CellSelector, FieldEncoding, LookupMethod, OutputFormat, SolverKind, SolverMode,
+            SpecEquation, VerificationInput, VerificationMethod,
+        },
+        report,
+    },
+};
+
+/// Command line interface of the `korrekt` binary.
+///
+/// Every analysis that used to be reachable through the numbered stdin menus in
+/// `io::analyzer_io` has a subcommand and flag equivalent, so the analyzer can be scripted in CI.
+/// The menus are still available with `--interactive`.
+#[derive(Debug, Parser)]
+#[command(name = "korrekt", version, about = "Static analysis of halo2 circuits")]
+pub struct Cli {
+    /// Name of the registered circuit to analyze (see the `circuits` subcommand).
+    #[arg(short, long, global = true)]
+    pub circuit: Option<String>,
+    /// The circuit is laid out over 2^k rows.
+    #[arg(short, long, global = true, default_value_t = 6)]
+    pub k: u32,
+    /// Choose the analysis and its options from the stdin menus instead of flags.
+    #[arg(short, long)]
+    pub interactive: bool,
+    /// Format of the report written once the analysis is done.
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormatArg::Text)]
+    pub format: OutputFormatArg,
+    /// Write the report to this file instead of stdout.
+    #[arg(short, long, global = true, value_name = "PATH")]
+    pub output: Option<PathBuf>,
+    /// Advice cells that are intentional hints, free to take several values, selected like `--output-cell`.
+    #[arg(long = "hint", global = true, value_name = "SELECTOR", value_parser = parse_cell_selector)]
+    pub hints: Vec<CellSelector>,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum Command {
+    /// Detect custom gates that are identically zero over every region.
+    UnusedGates,
+    /// Detect advice columns that are not queried by any gate.
+    UnusedColumns,
+    /// Detect assigned cells that do not occur in any enabled gate.
+    UnconstrainedCells,
+    /// Check whether the public inputs uniquely determine the witness.
+    Underconstrained(UnderconstrainedArgs),
+    /// Check which advice cells are uniquely determined by the public inputs.
+    CellDeterminism(CellDeterminismArgs),
+    /// Check that the constraints imply the relations between cells given with `--spec`.
+    Specification(SpecificationArgs),
+    /// List the names accepted by `--circuit`.
+    Circuits,
+}
+
+#[derive(Debug, Args)]
+pub struct UnderconstrainedArgs {
+    #[command(flatten)]
+    pub analysis: AnalysisArgs,
+    /// Write the witnesses of the under-constrained findings to this JSON file.
+    #[arg(long, value_name = "FILE")]
+    pub counterexample: Option<PathBuf>,
+    /// Advice cells that must be unique, all of them by default: `A-COL-ROW`, `column=INDEX`, `region=NAME` or
+    /// `annotation=NAME`.
+    #[arg(long = "output-cell", value_name = "SELECTOR", value_parser = parse_cell_selector)]
+    pub output_cells: Vec<CellSelector>,
+}
+
+/// Flags shared by the under-constrained, cell determinism and specification analyses.
+#[derive(Debug, Args)]
+pub struct AnalysisArgs {
+    /// How lookup arguments are encoded in the SMT query.
+    #[arg(long, value_enum, default_value_t = LookupMethodArg::InlineConstraints)]
+    pub lookup_method: LookupMethodArg,
+    /// Verify for the public inputs given with `--instance`, for random ones, or for all of them at once.
+    #[arg(long, value_enum, default_value_t = VerificationMethodArg::Random)]
+    pub verification_method: VerificationMethodArg,
+    /// Number of random public inputs to verify (`random` verification only).
+    #[arg(long, default_value_t = 1)]
+    pub iterations: u128,
+    /// Value of an instance cell, e.g. `--instance I-0-0=3` (`specific` verification only).
+    #[arg(long = "instance", value_name = "CELL=VALUE", value_parser = parse_instance)]
+    pub instances: Vec<(String, i64)>,
+    /// Keep one incremental solver session open, or rerun the solver on a file for every query.
+    #[arg(long, value_enum, default_value_t = SolverModeArg::Incremental)]
+    pub solver_mode: SolverModeArg,
+    /// SMT solver to run, its binary must be on PATH.
+    #[arg(long, value_enum, default_value_t = SolverArg::Cvc5)]
+    pub solver: SolverArg,
+    /// How field elements are encoded, z3 and bitwuzla need a non-native encoding.
+    #[arg(long, value_enum, default_value_t = EncodingArg::FiniteField)]
+    pub encoding: EncodingArg,
+    /// Directory of the SMT files, defaults to a `korrekt` directory in the system temporary directory.
+    #[arg(long, value_name = "DIR")]
+    pub smt_dir: Option<PathBuf>,
+    /// Keep the SMT files once the analysis is done.
+    #[arg(long)]
+    pub keep_smt: bool,
+    /// Time limit of each solver query in milliseconds, the analysis is inconclusive when it is hit.
+    #[arg(long, value_name = "MS")]
+    pub timeout: Option<u64>,
+    /// Resource limit of each solver query (cvc5 and z3 only), the analysis is inconclusive when it is hit.
+    #[arg(long, value_name = "N")]
+    pub resource_limit: Option<u64>,
+    /// Seed of the random values of the challenges, reported with the result to replay a run.
+    #[arg(long, value_name = "SEED")]
+    pub seed: Option<u64>,
+    /// List the constraints of an unsat core when no witness satisfies them, which slows down every query.
+    #[arg(long)]
+    pub unsat_cores: bool,
+    /// Precondition on an instance or advice cell, e.g. `--assume "I-0-0 < 2^64"`, `--assume "I-0-0 != 0"`,
+    /// `--assume "A-0-1 = 3"` or `--assume "I-0-1 in {0, 1}"`.
+    #[arg(long = "assume", value_name = "ASSUMPTION", value_parser = parse_assumption)]
+    pub assumptions: Vec<Assumption>,
+}
+
+#[derive(Debug, Args)]
+pub struct CellDeterminismArgs {
+    #[command(flatten)]
+    pub analysis: AnalysisArgs,
+    /// Only check the advice cells assigned in the regions with this name.
+    #[arg(long, conflicts_with = "column")]
+    pub region: Option<String>,
+    /// Only check the advice cells of the advice column with this index.
+    #[arg(long)]
+    pub column: Option<usize>,
+}
+
+impl CellDeterminismArgs {
+    /// The advice cells selected with `--region` or `--column`, all of them by default.
+    pub fn scope(&self) -> CellScope {
+        match (&self.region, self.column) {
+            (Some(region), _) => CellScope::Region(region.clone()),
+            (None, Some(column)) => CellScope::Column(column),
+            (None, None) => CellScope::All,
+        }
+    }
+}
+
+#[derive(Debug, Args)]
+pub struct SpecificationArgs {
+    #[command(flatten)]
+    pub analysis: AnalysisArgs,
+    /// Relation the constraints must imply, e.g. `--spec "I-0-0 = A-0-0 * A-1-0 + A-2-0"` or
+    /// `--spec "A-2-1 = xor(A-0-1, A-1-1, 8)"`.
+    #[arg(long = "spec", value_name = "EQUATION", required = true, value_parser = parse_spec)]
+    pub specifications: Vec<SpecEquation>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum LookupMethodArg {
+    /// Uninterpreted functions: fast, but may report false positives.
+    Uninterpreted,
+    /// Interpreted functions (range checks) over the lookup tables.
+    Interpreted,
+    /// Every lookup is expanded into inline constraints.
+    InlineConstraints,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum VerificationMethodArg {
+    Specific,
+    Random,
+    Global,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SolverModeArg {
+    Incremental,
+    File,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SolverArg {
+    Cvc5,
+    Yices,
+    Z3,
+    Bitwuzla,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum EncodingArg {
+    /// The native finite field theory (cvc5 and yices only).
+    FiniteField,
+    /// Non-linear integer arithmetic reduced modulo the prime.
+    Integer,
+    /// Bit-vectors as wide as the prime, reduced modulo the prime.
+    BitVector,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormatArg {
+    /// Human readable sentences: the status of the analysis followed by its findings.
+    Text,
+    /// JSON report with a versioned schema.
+    Json,
+    /// SARIF 2.1.0 log, for code scanning dashboards.
+    Sarif,
+}
+
+impl From<LookupMethodArg> for LookupMethod {
+    fn from(arg: LookupMethodArg) -> Self {
+        match arg {
+            LookupMethodArg::Uninterpreted => LookupMethod::Uninterpreted,
+            LookupMethodArg::Interpreted => LookupMethod::Interpreted,
+            LookupMethodArg::InlineConstraints => LookupMethod::InlineConstraints,
+        }
+    }
+}
+
+impl From<SolverModeArg> for SolverMode {
+    fn from(arg: SolverModeArg) -> Self {
+        match arg {
+            SolverModeArg::Incremental => SolverMode::Incremental,
+            SolverModeArg::File => SolverMode::File,
+        }
+    }
+}
+
+impl From<SolverArg> for SolverKind {
+    fn from(arg: SolverArg) -> Self {
+        match arg {
+            SolverArg::Cvc5 => SolverKind::Cvc5,
+            SolverArg::Yices => SolverKind::Yices,
+            SolverArg::Z3 => SolverKind::Z3,
+            SolverArg::Bitwuzla => SolverKind::Bitwuzla,
+        }
+    }
+}
+
+impl From<EncodingArg> for FieldEncoding {
+    fn from(arg: EncodingArg) -> Self {
+        match arg {
+            EncodingArg::FiniteField => FieldEncoding::FiniteField,
+            EncodingArg::Integer => FieldEncoding::Integer,
+            EncodingArg::BitVector => FieldEncoding::BitVector,
+        }
+    }
+}
+
+impl From<OutputFormatArg> for OutputFormat {
+    fn from(arg: OutputFormatArg) -> Self {
+        match arg {
+            OutputFormatArg::Text => OutputFormat::Text,
+            OutputFormatArg::Json => OutputFormat::Json,
+            OutputFormatArg::Sarif => OutputFormat::Sarif,
+        }
+    }
+}
+
+impl From<VerificationMethodArg> for VerificationMethod {
+    fn from(arg: VerificationMethodArg) -> Self {
+        match arg {
+            VerificationMethodArg::Specific => VerificationMethod::Specific,
+            VerificationMethodArg::Random => VerificationMethod::Random,
+            VerificationMethodArg::Global => VerificationMethod::Global,
+        }
+    }
+}
+
+fn parse_instance(s: &str) -> Result<(String, i64), String> {
+    let (cell, value) = s
+        .split_once('=')
+        .ok_or_else(|| format!("invalid CELL=VALUE: no `=` found in `{}`", s))?;
+    let value = value
+        .trim()
+        .parse::<i64>()
+        .map_err(|e| format!("invalid value for {}: {}", cell, e))?;
+    Ok((cell.trim().to_owned(), value))
+}
+
+fn parse_assumption(s: &str) -> Result<Assumption, String> {
+    s.parse().map_err(|e: anyhow::Error| e.to_string())
+}
+
+fn parse_spec(s: &str) -> Result<SpecEquation, String> {
+    s.parse().map_err(|e: anyhow::Error| e.to_string())
+}
+
+fn parse_cell_selector(s: &str) -> Result<CellSelector, String> {
+    if let Some((kind, value)) = s.split_once('=') {
+        return match kind.trim() {
+            "column" => value
+                .trim()
+                .parse::<usize>()
+                .map(CellSelector::Column)
+                .map_err(|e| format!("invalid column index {}: {}", value, e)),
+            "region" => Ok(CellSelector::Region(value.to_owned())),
+            "annotation" => Ok(CellSelector::Annotation(value.to_owned())),
+            _ => Err(format!(
+                "invalid selector `{}`: expected column=, region= or annotation=",
+                s
+            )),
+        };
+    }
+    let (column, row) = s
+        .strip_prefix("A-")
+        .and_then(|cell| cell.split_once('-'))
+        .ok_or_else(|| format!("invalid advice cell `{}`, expected A-COL-ROW", s))?;
+    match (column.parse::<usize>(), row.parse::<usize>()) {
+        (Ok(column), Ok(row)) => Ok(CellSelector::Cell { column, row }),
+        _ => Err(format!("invalid advice cell `{}`", s)),
+    }
+}
+
+impl AnalysisArgs {
+    /// Builds the `AnalyzerInput` equivalent to the answers of `retrieve_user_input_for_underconstrained`.
+    ///
+    /// For `specific` verification every instance cell of the circuit must be given a value with `--instance`.
+    pub fn analyzer_input(&self, instance_cells: &HashMap<String, i64>) -> Result<AnalyzerInput> {
+        let verification_method = VerificationMethod::from(self.verification_method);
+        let instances_string = match verification_method {
+            VerificationMethod::Specific => {
+                let specified: HashMap<String, i64> = self.instances.iter().cloned().collect();
+                for cell in specified.keys() {
+                    if !instance_cells.contains_key(cell) {
+                        return Err(anyhow!("{} is not an instance cell of the circuit", cell));
+                    }
+                }
+                for cell in instance_cells.keys() {
+                    if !specified.contains_key(cell) {
+                        return Err(anyhow!("Missing value for instance cell {}", cell));
+                    }
+                }
+                specified
+            }
+            VerificationMethod::Random | VerificationMethod::Global => instance_cells.clone(),
+        };
+        Ok(AnalyzerInput {
+            verification_method,
+            verification_input: VerificationInput {
+                instances_string,
+                iterations: self.iterations,
+            },
+            lookup_method: self.lookup_method.into(),
+        })
+    }
+
+    /// Builds the analyzer options given on the command line.
+    pub fn config(&self) -> AnalyzerConfig {
+        AnalyzerConfig {
+            solver_mode: self.solver_mode.into(),
+            solver: self.solver.into(),
+            field_encoding: self.encoding.into(),
+            output_dir: self.smt_dir.clone(),
+            keep_artifacts: self.keep_smt,
+            timeout: self.timeout.map(Duration::from_millis),
+            resource_limit: self.resource_limit,
+            seed: self.seed,
+            unsat_cores: self.unsat_cores,
+        }
+    }
+}
+
+/// Runs the analysis selected on the command line against `analyzer`.
+///
+/// Also returns the input of the under-constrained, cell determinism and specification analyses given with flags,
+/// which the text report mentions.
+pub fn run_analysis<F: AnalyzableField>(
+    cli: &Cli,
+    analyzer: &mut Analyzer<F>,
+) -> Result<(AnalyzerOutput, Option<AnalyzerInput>)> {
+    if cli.interactive {
+        let analyzer_type = retrieve_user_input_for_analyzer_type()
+            .context("Failed to retrieve the user inputs!")?;
+        return Ok((analyzer.dispatch_analysis(analyzer_type)?, None));
+    }
+    match cli
+        .command
+        .as_ref()
+        .context("No analysis selected, pass a subcommand or --interactive!")?
+    {
+        Command::UnusedGates => Ok((analyzer.dispatch_analysis(AnalyzerType::UnusedGates)?, None)),
+        Command::UnusedColumns => Ok((
+            analyzer.dispatch_analysis(AnalyzerType::UnusedColumns)?,
+            None,
+        )),
+        Command::UnconstrainedCells => Ok((
+            analyzer.dispatch_analysis(AnalyzerType::UnconstrainedCells)?,
+            None,
+        )),
+        Command::Underconstrained(args) => {
+            let analyzer_input = args
+                .analysis
+                .analyzer_input(&analyzer.instace_cells)
+                .context("Invalid arguments for under-constrained analysis!")?;
+            analyzer.config = args.analysis.config();
+            analyzer.output_cells = args.output_cells.clone();
+            analyzer.assumptions = args.analysis.assumptions.clone();
+            Ok((
+                analyzer.analyze_underconstrained(analyzer_input.clone())?,
+                Some(analyzer_input),
+            ))
+        }
+        Command::CellDeterminism(args) => {
+            let analyzer_input = args
+                .analysis
+                .analyzer_input(&analyzer.instace_cells)
+                .context("Invalid arguments for cell determinism analysis!")?;
+            analyzer.config = args.analysis.config();
+            analyzer.assumptions = args.analysis.assumptions.clone();
+            Ok((
+                analyzer.analyze_cell_determinism(analyzer_input.clone(), args.scope())?,
+                Some(analyzer_input),
+            ))
+        }
+        Command::Specification(args) => {
+            let analyzer_input = args
+                .analysis
+                .analyzer_input(&analyzer.instace_cells)
+                .context("Invalid arguments for specification analysis!")?;
+            // The report tells for which inputs the specifications were checked.
+            let analyzer_input = Analyzer::<F>::specification_input(analyzer_input);
+            analyzer.config = args.analysis.config();
+            analyzer.assumptions = args.analysis.assumptions.clone();
+            analyzer.specifications = args
+                .specifications
+                .iter()
+                .cloned()
+                .map(Specification::Equation)
+                .collect();
+            Ok((
+                analyzer.analyze_specification(analyzer_input.clone())?,
+                Some(analyzer_input),
+            ))
+        }
+        Command::Circuits => Err(anyhow!("The circuits subcommand does not run an analysis!")),
+    }
+}
+
+/// Entry point of the `korrekt` binary.
+///
+/// Parses the process arguments and analyzes the circuit of `registry` selected with `--circuit`.
+/// Downstream crates register their own circuits and call this from their `main`.
+pub fn run<F: AnalyzableField>(registry: &CircuitRegistry<F>) -> Result<()> {
+    run_from(registry, std::env::args_os())
+}
+
+/// Same as `run`, with the command line given explicitly.
+pub fn run_from<F, I, T>(registry: &CircuitRegistry<F>, args: I) -> Result<()>
+where
+    F: AnalyzableField,
+    I: IntoIterator<Item = T>,
+    T: Into<OsString> + Clone,
+{
+    let _ = env_logger::Builder::from_env(Env::default().default_filter_or("info")).try_init();
+
+    let cli = Cli::parse_from(args);
+
+    if matches!(cli.command, Some(Command::Circuits)) {
+        for name in registry.names() {
+            println!("{}", name);
+        }
+        return Ok(());
+    }
+
+    let name = cli
+        .circuit
+        .as_deref()
+        .context("No circuit selected, pass --circuit <name>!")?;
+    let mut analyzer = registry.build(name, cli.k)?;
+    analyzer.hint_cells = cli.hints.clone();
+
+    let (analyzer_output, analyzer_input) =
+        run_analysis(&cli, &mut analyzer).context("Failed to perform analysis!")?;
+    write_report(&cli, analyzer_input.as_ref(), &analyzer_output)?;
+    write_counterexamples(&cli, &analyzer, &analyzer_output)
+}
+
+/// Writes the counterexamples of `analyzer_output` to the file given with `--counterexample`, if any.
+pub fn write_counterexamples<F: AnalyzableField>(
+    cli: &Cli,
+    analyzer: &Analyzer<F>,
+    analyzer_output: &AnalyzerOutput,
+) -> Result<()> {
+    let path = match &cli.command {
+        Some(Command::Underconstrained(UnderconstrainedArgs {
+            counterexample: Some(path),
+            ..
+        })) => path,
+        _ => return Ok(()),
+    };
+    let json = report::counterexamples_to_json(&analyzer.counterexamples(analyzer_output))?;
+    std::fs::write(path, json)
+        .with_context(|| format!("Failed to write counterexample file {}!", path.display()))
+}
+
+/// Writes the report of `analyzer_output` in the format selected with `--format`, to the file given with
+/// `--output` or to stdout.
+///
+/// The analyses themselves do not print to stdout, so a JSON or SARIF report on stdout can be parsed as is.
+pub fn write_report(
+    cli: &Cli,
+    analyzer_input: Option<&AnalyzerInput>,
+    analyzer_output: &AnalyzerOutput,
+) -> Result<()> {
+    let format = OutputFormat::from(cli.format);
+    match &cli.output {
+        Some(path) => {
+            let mut file = File::create(path)
+                .with_context(|| format!("Failed to create report file {}!", path.display()))?;
+            output_result(analyzer_input, analyzer_output, format, &mut file)
+        }
+        None => output_result(analyzer_input, analyzer_output, format, &mut io::stdout()),
+    }
+}
@@ -1,10 +1,11 @@
 use anyhow::{anyhow, Context, Result};
-use std::{collections::HashMap, io};
+use std::{collections::HashMap, io, io::Write};
 use crate::{
     circuit_analyzer::{analyzable::AnalyzableField,halo2_proofs_libs::*},
     io::analyzer_io_type::{
-        AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, LookupMethod, VerificationInput, VerificationMethod
+        AnalyzerInput, AnalyzerOutput, AnalyzerOutputStatus, AnalyzerType, LookupMethod, OutputFormat, SpecEquation, VerificationInput, VerificationMethod
     },
+    io::report,
 };
 /// Retrieves user input for underconstrained circuit analysis.
 ///
@@ -63,10 +64,12 @@ pub fn retrieve_user_input_for_underconstrained<F: AnalyzableField>(
     println!("You can verify the circuit for a specific public input or a random number of public inputs:");
     println!("1. verify the circuit for a specific public input!");
     println!("2. Verify for a random number of public inputs!");
+    println!("3. Verify for all public inputs at once!");
 
     let mut menu = String::new();
     const SPECIFIC: i64 = 1;
     const RANDOM: i64 = 2;
+    const GLOBAL: i64 = 3;
     io::stdin()
         .read_line(&mut menu)
         .expect("Failed to read line");
@@ -119,63 +122,164 @@ pub fn retrieve_user_input_for_underconstrained<F: AnalyzableField>(
             analyzer_input.verification_input.iterations = iterations;
             Ok(analyzer_input)
         }
+        GLOBAL => {
+            analyzer_input.verification_method = VerificationMethod::Global;
+            analyzer_input.verification_input.instances_string = instance_cols_string.clone();
+            Ok(analyzer_input)
+        }
         _ => Err(anyhow!("Option {} Is Invalid", verification_type)),
     }
 }
+/// Retrieves the specifications of the circuit for the specification analysis.
+///
+/// This function prompts the user for the relations the circuit must enforce, one `SpecEquation` per line,
+/// until an empty line.
+///
+pub fn retrieve_user_input_for_specification() -> Result<Vec<SpecEquation>> {
+    println!("Enter the relations the circuit must enforce, one per line (e.g. I-0-0 = A-0-0 * A-1-0 + A-2-0), followed by an empty line:");
+    let mut specifications = vec![];
+    loop {
+        let mut line = String::new();
+        io::stdin()
+            .read_line(&mut line)
+            .expect("Failed to read line");
+        if line.trim().is_empty() {
+            return Ok(specifications);
+        }
+        specifications.push(
+            line.trim()
+                .parse::<SpecEquation>()
+                .context("Failed to retrieve the specification!")?,
+        );
+    }
+}
 /// Outputs the result of the analysis.
 ///
-/// This function takes an `AnalyzerInput` and `AnalyzerOutput` as input and prints the corresponding
-/// result message based on the `AnalyzerOutputStatus` in the `AnalyzerOutput` struct.
+/// With `OutputFormat::Text`, this function prints the result message corresponding to the `AnalyzerOutputStatus`
+/// in the `AnalyzerOutput` struct, followed by the findings. The `AnalyzerInput` of an under-constrained analysis is
+/// used to tell for how many inputs the circuit was checked.
+/// With `OutputFormat::Json` and `OutputFormat::Sarif`, it writes the report built by `io::report` instead.
 ///
-pub fn output_result(analyzer_input: AnalyzerInput, analyzer_output: &AnalyzerOutput) {
-    match analyzer_output.output_status {
+pub fn output_result(
+    analyzer_input: Option<&AnalyzerInput>,
+    analyzer_output: &AnalyzerOutput,
+    format: OutputFormat,
+    out: &mut dyn Write,
+) -> Result<()> {
+    match format {
+        OutputFormat::Text => {
+            write_text_result(analyzer_input, analyzer_output, out)
+                .context("Failed to write the analysis result!")?;
+        }
+        OutputFormat::Json => {
+            writeln!(out, "{}", report::to_json(analyzer_output)?)
+                .context("Failed to write the JSON report!")?;
+        }
+        OutputFormat::Sarif => {
+            writeln!(out, "{}", report::to_sarif(analyzer_output)?)
+                .context("Failed to write the SARIF report!")?;
+        }
+    }
+    Ok(())
+}
+
+fn write_text_result(
+    analyzer_input: Option<&AnalyzerInput>,
+    analyzer_output: &AnalyzerOutput,
+    out: &mut dyn Write,
+) -> io::Result<()> {
+    let inputs = match analyzer_input {
+        Some(analyzer_input) => match analyzer_input.verification_method {
+            VerificationMethod::Specific => "this specific input".to_owned(),
+            VerificationMethod::Random => format!(
+                "{} random input(s)",
+                analyzer_input.verification_input.iterations
+            ),
+            VerificationMethod::Global => "any input".to_owned(),
+        },
+        None => "the given input(s)".to_owned(),
+    };
+    let count = analyzer_output.findings.len();
+    match &analyzer_output.output_status {
         AnalyzerOutputStatus::Underconstrained => {
-            println!("The circuit is under-constrained.");
+            writeln!(out, "The circuit is under-constrained.")?;
         }
         AnalyzerOutputStatus::Overconstrained => {
-            println!("The circuit is over-constrained");
+            writeln!(out, "The circuit is over-constrained")?;
         }
         AnalyzerOutputStatus::NotUnderconstrained => {
-            println!("The circuit is not under-constrained!");
+            writeln!(out, "The circuit is not under-constrained!")?;
         }
         AnalyzerOutputStatus::NotUnderconstrainedLocal => {
-            match analyzer_input.verification_method {
-                VerificationMethod::Specific => {
-                    println!("The circuit is not under-constrained for this specific input.");
-                }
-                VerificationMethod::Random => {
-                    println!(
-                        "The circuit is not under-constrained for {} random input(s).",
-                        analyzer_input.verification_input.iterations
-                    );
-                }
-            }
+            writeln!(out, "The circuit is not under-constrained for {}.", inputs)?;
+        }
+        AnalyzerOutputStatus::UnusedCustomGates | AnalyzerOutputStatus::NoUnusedCustomGates => {
+            writeln!(out, "Finished analysis: {} unused gates found.", count)?;
+        }
+        AnalyzerOutputStatus::UnconstrainedCells | AnalyzerOutputStatus::NoUnconstrainedCells => {
+            writeln!(
+                out,
+                "Finished analysis: {} unconstrained cells found.",
+                count
+            )?;
+        }
+        AnalyzerOutputStatus::UnusedColumns | AnalyzerOutputStatus::NoUnusedColumns => {
+            writeln!(out, "Finished analysis: {} unused columns found.", count)?;
         }
-        AnalyzerOutputStatus::UnusedCustomGates => {}
-        AnalyzerOutputStatus::UnconstrainedCells => {}
-        AnalyzerOutputStatus::UnusedColumns => {}
         AnalyzerOutputStatus::Invalid => {
-            println!("The analyzer output is invalid.");
+            writeln!(out, "The analyzer output is invalid.")?;
+        }
+        AnalyzerOutputStatus::FreeCells => {
+            writeln!(
+                out,
+                "{} checked advice cell(s) are not determined by the public input for {}.",
+                count, inputs
+            )?;
+        }
+        AnalyzerOutputStatus::NoFreeCells => {
+            writeln!(
+                out,
+                "Every checked advice cell is determined by the public input for {}.",
+                inputs
+            )?;
+        }
+        AnalyzerOutputStatus::SpecificationHolds => {
+            writeln!(
+                out,
+                "The circuit satisfies its specification for {}.",
+                inputs
+            )?;
+        }
+        AnalyzerOutputStatus::SpecificationViolated => {
+            writeln!(out, "The circuit violates its specification.")?;
         }
-        AnalyzerOutputStatus::NoUnusedCustomGates => {}
-        AnalyzerOutputStatus::NoUnconstrainedCells => {}
-        AnalyzerOutputStatus::NoUnusedColumns => {}
         AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups => {
-            match analyzer_input.verification_method {
-                VerificationMethod::Specific => {
-                    println!("\nTwo assignments found to advice columns, making the circuit under-constrained for this specific input. But the assignmets are not valid in lookup table(s)!
-                    \nProbably a false positive.\n");
-                }
-                VerificationMethod::Random => {
-                    println!(
-                        "\nTwo assignments found to advice columns, making the circuit under-constrained for {} random input(s). But the assignmets are not valid in lookup table(s)!
-                        \nProbably a false positive.\n",
-                        analyzer_input.verification_input.iterations
-                    );
-                }
-            }
-        },
+            writeln!(out, "\nTwo assignments found to advice columns, making the circuit under-constrained for {}. But the assignmets are not valid in lookup table(s)!
+                    \nProbably a false positive.\n", inputs)?;
+        }
+        AnalyzerOutputStatus::Inconclusive { reason } => {
+            writeln!(
+                out,
+                "The analysis is inconclusive, the solver answered unknown ({}).",
+                reason
+            )?;
+        }
+    }
+    if !analyzer_output.assumptions.is_empty() {
+        let assumptions: Vec<String> = analyzer_output
+            .assumptions
+            .iter()
+            .map(ToString::to_string)
+            .collect();
+        writeln!(out, "Assuming {}.", assumptions.join(", "))?;
     }
+    if let Some(challenges) = &analyzer_output.challenges {
+        writeln!(out, "With the challenges {}.", challenges)?;
+    }
+    for finding in &analyzer_output.findings {
+        writeln!(out, "{}", finding.to_string().trim_end())?;
+    }
+    Ok(())
 }
 /// Retrieves user input to determine the type of analysis for the circuit.
 ///
@@ -187,12 +291,16 @@ pub fn retrieve_user_input_for_analyzer_type() -> Result<AnalyzerType> {
     const UNUSED_COLUMNS: i64 = 2;
     const UNCONSTRAINED_CELLS: i64 = 3;
     const UNDERCONSTRAINED_CIRCUITS: i64 = 4;
+    const CELL_DETERMINISM: i64 = 5;
+    const SPECIFICATION: i64 = 6;
 
     println!("Choose the mode of analysis for your circuit.");
     println!("1. Unused Gates");
     println!("2. Unused Columns");
     println!("3. Unconstrained Cells");
     println!("4. Underconstrained Circuit");
+    println!("5. Cell Determinism");
+    println!("6. Specification");
 
     let mut menu = String::new();
     io::stdin()
@@ -217,6 +325,12 @@ pub fn retrieve_user_input_for_analyzer_type() -> Result<AnalyzerType> {
         UNDERCONSTRAINED_CIRCUITS => {
             analyzer_type = AnalyzerType::UnderconstrainedCircuit;
         }
+        CELL_DETERMINISM => {
+            analyzer_type = AnalyzerType::CellDeterminism;
+        }
+        SPECIFICATION => {
+            analyzer_type = AnalyzerType::Specification;
+        }
         _ => {
             panic!("Not a valid mode of analysis.")
         }
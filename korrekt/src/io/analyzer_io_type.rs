@@ -1,30 +1,118 @@
-use std::collections::HashMap;
-#[derive(Debug, PartialEq, Eq)]
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt,
+    path::PathBuf,
+    str::FromStr,
+    time::Duration,
+};
+
+use anyhow::{anyhow, Context};
+use num::BigUint;
+use serde::Serialize;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum VerificationMethod {
     Specific,
     Random,
+    /// All public inputs at once, with two copies of the constraints that share the instance cells.
+    Global,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct VerificationInput {
     pub instances_string: HashMap<String, i64>,
     pub iterations: u128,
 }
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum LookupMethod {
     Uninterpreted,
     Interpreted,
     InlineConstraints,
     Invalid
 }
-#[derive(Debug)]
+/// The SMT solver used by the under-constrained analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverKind {
+    Cvc5,
+    /// Yices 2, built with finite field support (MCSAT).
+    Yices,
+    Z3,
+    Bitwuzla,
+}
+
+/// How field elements and field arithmetic are encoded in SMT-LIB.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldEncoding {
+    /// The `(_ FiniteField p)` sort and its `ff.add`/`ff.mul`/`ff.neg` operators.
+    FiniteField,
+    /// Integers in `[0, p)`, with every operation reduced `mod p`.
+    Integer,
+    /// Bit-vectors wide enough to hold `p - 1`, with every operation reduced with `bvurem`.
+    BitVector,
+}
+
+/// How the SMT solver is run during the under-constrained analysis.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SolverMode {
+    /// A single solver process is queried incrementally over stdin/stdout.
+    Incremental,
+    /// The solver is run from scratch on a file for every query.
+    File,
+}
+
+/// Options of the under-constrained analysis that do not depend on the circuit.
+#[derive(Debug, Clone)]
+pub struct AnalyzerConfig {
+    /// How the SMT solver is run.
+    pub solver_mode: SolverMode,
+    /// The SMT solver used.
+    pub solver: SolverKind,
+    /// How field elements are encoded in the SMT file.
+    pub field_encoding: FieldEncoding,
+    /// Directory of the SMT files, a `korrekt` directory in the system temporary directory if `None`.
+    pub output_dir: Option<PathBuf>,
+    /// Keep the SMT files once the analysis is done, e.g. to replay a query by hand.
+    pub keep_artifacts: bool,
+    /// Time limit of each solver query, unlimited if `None`.
+    pub timeout: Option<Duration>,
+    /// Solver specific resource limit of each query (cvc5 and z3 only), unlimited if `None`.
+    ///
+    /// Unlike the timeout, the result does not depend on the load of the machine.
+    pub resource_limit: Option<u64>,
+    /// Seed of the random values the challenges are fixed to, a fresh seed if `None`.
+    ///
+    /// The seed of a run is reported with its result, so that the run can be replayed.
+    pub seed: Option<u64>,
+    /// Name the assertions so that an over-constrained result lists the constraints of an unsat core.
+    ///
+    /// Off by default, since tracking the assertions slows down every query.
+    pub unsat_cores: bool,
+}
+
+impl Default for AnalyzerConfig {
+    fn default() -> Self {
+        AnalyzerConfig {
+            solver_mode: SolverMode::Incremental,
+            solver: SolverKind::Cvc5,
+            field_encoding: FieldEncoding::FiniteField,
+            output_dir: None,
+            keep_artifacts: false,
+            timeout: None,
+            resource_limit: None,
+            seed: None,
+            unsat_cores: false,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
 pub struct AnalyzerInput {
     pub verification_method: VerificationMethod,
     pub verification_input: VerificationInput,
     pub lookup_method: LookupMethod
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, Serialize)]
 pub enum AnalyzerOutputStatus {
     Invalid,
     Underconstrained,
@@ -37,12 +125,597 @@ pub enum AnalyzerOutputStatus {
     UnconstrainedCells,
     NoUnconstrainedCells,
     UnusedColumns,
-    NoUnusedColumns
+    NoUnusedColumns,
+    /// The solver answered `unknown` to a query, e.g. because it hit the timeout, for the given reason.
+    Inconclusive { reason: String },
+    FreeCells,
+    NoFreeCells,
+    /// The constraints imply every specification of the circuit.
+    SpecificationHolds,
+    /// A witness satisfies the constraints but not one of the specifications.
+    SpecificationViolated,
 }
 
-#[derive(Debug)]
+/// Values of the SMT variables in a model, keyed by variable name (e.g. `A-0-1`, `I-0-0`).
+pub type WitnessModel = BTreeMap<String, String>;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ColumnType {
+    Advice,
+    Fixed,
+    Instance,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct ColumnLocation {
+    pub column_type: ColumnType,
+    pub index: usize,
+}
+
+impl fmt::Display for ColumnLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} column {}", self.column_type, self.index)
+    }
+}
+
+/// An advice cell, together with where it is assigned in the circuit.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct CellLocation {
+    /// The SMT variable of the cell (e.g. `A-1-4`).
+    pub cell: String,
+    /// Name of the region the cell is assigned in, if any.
+    pub region_name: Option<String>,
+    pub column: ColumnLocation,
+    /// Annotation of the column in the region, if the circuit gave one.
+    pub annotation: Option<String>,
+    /// Absolute row of the cell.
+    pub row: usize,
+}
+
+impl fmt::Display for CellLocation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.cell, self.column)?;
+        if let Some(annotation) = &self.annotation {
+            write!(f, " (\"{}\")", annotation)?;
+        }
+        write!(f, " at row {}", self.row)?;
+        match &self.region_name {
+            Some(region_name) => write!(f, " in \"{}\" region", region_name),
+            None => write!(f, " outside of any region"),
+        }
+    }
+}
+
+/// A precondition on an instance (`I-col-row`) or advice (`A-col-row`) cell, under which the circuit is analyzed.
+///
+/// It is written `CELL = VALUE`, `CELL != VALUE`, `CELL in {VALUE, ...}` or `CELL < 2^BITS`, the values being field
+/// elements given as (possibly negative) integers.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Assumption {
+    /// The cell equals the value.
+    Equal { cell: String, value: i64 },
+    /// The cell differs from the value, e.g. an input that must be non-zero.
+    NotEqual { cell: String, value: i64 },
+    /// The cell equals one of the values.
+    OneOf { cell: String, values: Vec<i64> },
+    /// The cell is less than 2^`bits`, e.g. a 64-bit input.
+    BitLength { cell: String, bits: u32 },
+}
+
+impl Assumption {
+    /// The cell the assumption is about.
+    pub fn cell(&self) -> &str {
+        match self {
+            Assumption::Equal { cell, .. }
+            | Assumption::NotEqual { cell, .. }
+            | Assumption::OneOf { cell, .. }
+            | Assumption::BitLength { cell, .. } => cell,
+        }
+    }
+}
+
+impl fmt::Display for Assumption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Assumption::Equal { cell, value } => write!(f, "{} = {}", cell, value),
+            Assumption::NotEqual { cell, value } => write!(f, "{} != {}", cell, value),
+            Assumption::OneOf { cell, values } => {
+                let values: Vec<String> = values.iter().map(i64::to_string).collect();
+                write!(f, "{} in {{{}}}", cell, values.join(", "))
+            }
+            Assumption::BitLength { cell, bits } => write!(f, "{} < 2^{}", cell, bits),
+        }
+    }
+}
+
+impl FromStr for Assumption {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let parse_value = |value: &str| {
+            value
+                .trim()
+                .parse::<i64>()
+                .with_context(|| format!("Invalid value {} in assumption `{}`!", value.trim(), s))
+        };
+        if let Some((cell, values)) = s.split_once(" in ") {
+            let values = values
+                .trim()
+                .strip_prefix('{')
+                .and_then(|values| values.strip_suffix('}'))
+                .with_context(|| format!("Expected a set {{VALUE, ...}} in assumption `{}`!", s))?;
+            return Ok(Assumption::OneOf {
+                cell: cell.trim().to_owned(),
+                values: values
+                    .split(',')
+                    .map(parse_value)
+                    .collect::<anyhow::Result<_>>()?,
+            });
+        }
+        if let Some((cell, value)) = s.split_once("!=") {
+            return Ok(Assumption::NotEqual {
+                cell: cell.trim().to_owned(),
+                value: parse_value(value)?,
+            });
+        }
+        if let Some((cell, value)) = s.split_once('=') {
+            return Ok(Assumption::Equal {
+                cell: cell.trim().to_owned(),
+                value: parse_value(value)?,
+            });
+        }
+        if let Some((cell, bound)) = s.split_once('<') {
+            let bits = bound
+                .trim()
+                .strip_prefix("2^")
+                .and_then(|bits| bits.parse::<u32>().ok())
+                .with_context(|| format!("Expected a bound 2^BITS in assumption `{}`!", s))?;
+            return Ok(Assumption::BitLength {
+                cell: cell.trim().to_owned(),
+                bits,
+            });
+        }
+        Err(anyhow!(
+            "Invalid assumption `{}`, expected CELL = VALUE, CELL != VALUE, CELL in {{VALUE, ...}} or CELL < 2^BITS!",
+            s
+        ))
+    }
+}
+
+/// A term of the specification language over instance (`I-col-row`) and advice (`A-col-row`) cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpecTerm {
+    Cell(String),
+    Constant(BigUint),
+    Neg(Box<SpecTerm>),
+    Add(Box<SpecTerm>, Box<SpecTerm>),
+    Sub(Box<SpecTerm>, Box<SpecTerm>),
+    Mul(Box<SpecTerm>, Box<SpecTerm>),
+    /// The bitwise XOR of two terms that must be less than 2^`bits`, or the specification is violated.
+    Xor {
+        left: Box<SpecTerm>,
+        right: Box<SpecTerm>,
+        bits: u32,
+    },
+}
+
+impl SpecTerm {
+    /// Whether the term is a sum or a difference, which needs parentheses as an operand of another term.
+    fn is_sum(&self) -> bool {
+        matches!(self, SpecTerm::Add(..) | SpecTerm::Sub(..))
+    }
+}
+
+impl fmt::Display for SpecTerm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SpecTerm::Cell(cell) => write!(f, "{}", cell),
+            SpecTerm::Constant(value) => write!(f, "{}", value),
+            SpecTerm::Neg(term) if term.is_sum() || matches!(**term, SpecTerm::Mul(..)) => {
+                write!(f, "-({})", term)
+            }
+            SpecTerm::Neg(term) => write!(f, "-{}", term),
+            SpecTerm::Add(left, right) if right.is_sum() => write!(f, "{} + ({})", left, right),
+            SpecTerm::Add(left, right) => write!(f, "{} + {}", left, right),
+            SpecTerm::Sub(left, right) if right.is_sum() => write!(f, "{} - ({})", left, right),
+            SpecTerm::Sub(left, right) => write!(f, "{} - {}", left, right),
+            SpecTerm::Mul(left, right) => {
+                if left.is_sum() {
+                    write!(f, "({}) * ", left)?;
+                } else {
+                    write!(f, "{} * ", left)?;
+                }
+                if right.is_sum() || matches!(**right, SpecTerm::Mul(..)) {
+                    write!(f, "({})", right)
+                } else {
+                    write!(f, "{}", right)
+                }
+            }
+            SpecTerm::Xor { left, right, bits } => write!(f, "xor({}, {}, {})", left, right, bits),
+        }
+    }
+}
+
+/// A relation `LHS = RHS` between cells that the constraints of a circuit are expected to imply, e.g. that an output
+/// instance is `a*b + c`: `I-0-0 = A-0-0 * A-1-0 + A-2-0`.
+///
+/// Both sides are terms over instance (`I-col-row`) and advice (`A-col-row`) cells and non-negative integer
+/// constants, with `+`, `-`, `*`, parentheses and `xor(X, Y, BITS)`, the bitwise XOR of two terms that must be less
+/// than 2^BITS for the relation to hold. The arithmetic is the arithmetic of the field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpecEquation {
+    pub lhs: SpecTerm,
+    pub rhs: SpecTerm,
+}
+
+impl fmt::Display for SpecEquation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} = {}", self.lhs, self.rhs)
+    }
+}
+
+impl FromStr for SpecEquation {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> anyhow::Result<Self> {
+        let mut parser = SpecParser {
+            input: s,
+            position: 0,
+        };
+        let lhs = parser.sum()?;
+        parser.expect("=")?;
+        let rhs = parser.sum()?;
+        parser.skip_whitespace();
+        if parser.position < s.len() {
+            return Err(parser.error("end of the specification"));
+        }
+        Ok(SpecEquation { lhs, rhs })
+    }
+}
+
+/// Recursive descent parser of `SpecEquation`, `position` being the byte offset of the next token in `input`.
+struct SpecParser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> SpecParser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.input[self.position..]
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = self.rest();
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn error(&self, expected: &str) -> anyhow::Error {
+        anyhow!(
+            "Expected {} at offset {} in specification `{}`!",
+            expected,
+            self.position,
+            self.input
+        )
+    }
+
+    /// Consumes `token` if it comes next.
+    fn eat(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        if self.rest().starts_with(token) {
+            self.position += token.len();
+            return true;
+        }
+        false
+    }
+
+    fn expect(&mut self, token: &str) -> anyhow::Result<()> {
+        if self.eat(token) {
+            Ok(())
+        } else {
+            Err(self.error(&format!("`{}`", token)))
+        }
+    }
+
+    /// Consumes the longest prefix of the rest of the input whose characters satisfy `predicate`.
+    fn take_while(&mut self, predicate: impl Fn(char) -> bool) -> &'a str {
+        let rest = self.rest();
+        let length = rest.find(|c: char| !predicate(c)).unwrap_or(rest.len());
+        self.position += length;
+        &rest[..length]
+    }
+
+    fn number<T: FromStr>(&mut self) -> anyhow::Result<T> {
+        self.skip_whitespace();
+        let digits = self.take_while(|c| c.is_ascii_digit());
+        digits.parse().map_err(|_| self.error("a number"))
+    }
+
+    /// sum := product (('+' | '-') product)*
+    fn sum(&mut self) -> anyhow::Result<SpecTerm> {
+        let mut term = self.product()?;
+        loop {
+            if self.eat("+") {
+                term = SpecTerm::Add(Box::new(term), Box::new(self.product()?));
+            } else if self.eat("-") {
+                term = SpecTerm::Sub(Box::new(term), Box::new(self.product()?));
+            } else {
+                return Ok(term);
+            }
+        }
+    }
+
+    /// product := unary ('*' unary)*
+    fn product(&mut self) -> anyhow::Result<SpecTerm> {
+        let mut term = self.unary()?;
+        while self.eat("*") {
+            term = SpecTerm::Mul(Box::new(term), Box::new(self.unary()?));
+        }
+        Ok(term)
+    }
+
+    /// unary := '-' unary | '(' sum ')' | 'xor(' sum ',' sum ',' BITS ')' | CELL | CONSTANT
+    fn unary(&mut self) -> anyhow::Result<SpecTerm> {
+        if self.eat("-") {
+            return Ok(SpecTerm::Neg(Box::new(self.unary()?)));
+        }
+        if self.eat("(") {
+            let term = self.sum()?;
+            self.expect(")")?;
+            return Ok(term);
+        }
+        if self.eat("xor") {
+            self.expect("(")?;
+            let left = self.sum()?;
+            self.expect(",")?;
+            let right = self.sum()?;
+            self.expect(",")?;
+            let bits = self.number()?;
+            self.expect(")")?;
+            return Ok(SpecTerm::Xor {
+                left: Box::new(left),
+                right: Box::new(right),
+                bits,
+            });
+        }
+        self.skip_whitespace();
+        if self.rest().starts_with("A-") || self.rest().starts_with("I-") {
+            let start = self.position;
+            self.position += 2;
+            let column = self.take_while(|c| c.is_ascii_digit());
+            let separator = self.take_while(|c| c == '-');
+            let row = self.take_while(|c| c.is_ascii_digit());
+            if column.is_empty() || separator.len() != 1 || row.is_empty() {
+                self.position = start;
+                return Err(self.error("a cell A-COL-ROW or I-COL-ROW"));
+            }
+            return Ok(SpecTerm::Cell(self.input[start..self.position].to_owned()));
+        }
+        self.number().map(SpecTerm::Constant).map_err(|_| {
+            self.error("a cell A-COL-ROW or I-COL-ROW, a constant, `-`, `(` or `xor(`")
+        })
+    }
+}
+
+/// The constraint of the circuit an SMT assertion is generated from.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ConstraintOrigin {
+    /// A polynomial of a custom gate, at an absolute row of a region.
+    Gate {
+        gate_index: usize,
+        gate_name: String,
+        polynomial_index: usize,
+        region_name: String,
+        row: usize,
+    },
+    /// A lookup argument, at an absolute row of a region.
+    Lookup {
+        lookup_index: usize,
+        region_name: String,
+        row: usize,
+    },
+    /// A shuffle argument, over all usable rows.
+    Shuffle { shuffle_index: usize },
+    /// A copy constraint between two cells.
+    Copy { left: String, right: String },
+    /// The value given to an instance cell.
+    PublicInput { cell: String, value: i64 },
+    /// A precondition given by the user.
+    Assumption { assumption: Assumption },
+}
+
+impl fmt::Display for ConstraintOrigin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConstraintOrigin::Gate {
+                gate_name,
+                polynomial_index,
+                region_name,
+                row,
+                ..
+            } => write!(
+                f,
+                "gate \"{}\" (polynomial {}) in \"{}\" region at row {}",
+                gate_name, polynomial_index, region_name, row
+            ),
+            ConstraintOrigin::Lookup {
+                lookup_index,
+                region_name,
+                row,
+            } => write!(
+                f,
+                "lookup {} in \"{}\" region at row {}",
+                lookup_index, region_name, row
+            ),
+            ConstraintOrigin::Shuffle { shuffle_index } => write!(f, "shuffle {}", shuffle_index),
+            ConstraintOrigin::Copy { left, right } => {
+                write!(f, "copy constraint {} = {}", left, right)
+            }
+            ConstraintOrigin::PublicInput { cell, value } => {
+                write!(f, "public input {} = {}", cell, value)
+            }
+            ConstraintOrigin::Assumption { assumption } => write!(f, "assumption {}", assumption),
+        }
+    }
+}
+
+/// A single issue reported by an analysis, together with its location in the circuit.
+///
+/// In JSON reports a finding is an object whose `kind` is the snake case name of the variant.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Finding {
+    /// A custom gate that is identically zero over every region.
+    UnusedGate { gate_index: usize, gate_name: String },
+    /// An advice column query that does not occur in any gate.
+    UnusedColumn {
+        column: ColumnLocation,
+        rotation: i32,
+    },
+    /// An assigned cell that does not occur in any gate enabled in its region.
+    UnconstrainedCell {
+        region_index: usize,
+        region_name: String,
+        column: ColumnLocation,
+        /// Absolute row of the cell.
+        row: usize,
+        /// Offset of the cell from the first row of its region.
+        rotation: i32,
+    },
+    /// Two different witnesses that satisfy the constraints for the same public input.
+    Underconstrained {
+        model: WitnessModel,
+        /// Differs from `model` in the `differing_cells` only.
+        equivalent_model: WitnessModel,
+        /// A minimal set of advice cells that can take other values while every other advice cell is unchanged.
+        ///
+        /// Only the head of each copy cycle is listed.
+        differing_cells: Vec<CellLocation>,
+    },
+    /// An advice cell that can take two values for the same public input.
+    FreeCell { cell: CellLocation },
+    /// A witness that satisfies the constraints but not the specification.
+    SpecificationViolation {
+        specification: String,
+        model: WitnessModel,
+    },
+    /// No witness satisfies the constraints for the public input.
+    Overconstrained {
+        /// The constraints of an unsat core, empty unless `AnalyzerConfig::unsat_cores` is set and the solver
+        /// reports one.
+        conflicting_constraints: Vec<ConstraintOrigin>,
+    },
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Finding::UnusedGate { gate_name, .. } => write!(
+                f,
+                "unused gate: \"{}\" (consider removing the gate or checking selectors in regions)",
+                gate_name
+            ),
+            Finding::UnusedColumn { column, rotation } => {
+                write!(f, "unused column: {} (rotation: {})", column, rotation)
+            }
+            Finding::UnconstrainedCell {
+                region_name,
+                column,
+                row,
+                rotation,
+                ..
+            } => write!(
+                f,
+                "unconstrained cell in \"{}\" region: {} at row {} (rotation: {}) -- very likely a bug.",
+                region_name, column, row, rotation
+            ),
+            Finding::Underconstrained {
+                model,
+                equivalent_model,
+                differing_cells,
+            } => {
+                writeln!(f, "two witnesses for the same public input:")?;
+                for (var, value) in model {
+                    let other = equivalent_model.get(var).map(String::as_str).unwrap_or("?");
+                    if value == other {
+                        writeln!(f, "  {} : {}", var, value)?;
+                    } else {
+                        writeln!(f, "  {} : {} != {}", var, value, other)?;
+                    }
+                }
+                writeln!(f, "advice cells that can differ:")?;
+                for cell in differing_cells {
+                    writeln!(f, "  {}", cell)?;
+                }
+                Ok(())
+            }
+            Finding::FreeCell { cell } => {
+                write!(f, "cell not determined by the public input: {}", cell)
+            }
+            Finding::SpecificationViolation {
+                specification,
+                model,
+            } => {
+                writeln!(f, "witness violating the specification {}:", specification)?;
+                for (var, value) in model {
+                    writeln!(f, "  {} : {}", var, value)?;
+                }
+                Ok(())
+            }
+            Finding::Overconstrained {
+                conflicting_constraints,
+            } => {
+                if conflicting_constraints.is_empty() {
+                    return writeln!(f, "no witness satisfies the constraints (no unsat core)");
+                }
+                writeln!(f, "no witness satisfies these constraints together:")?;
+                for constraint in conflicting_constraints {
+                    writeln!(f, "  {}", constraint)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
 pub struct AnalyzerOutput {
     pub output_status: AnalyzerOutputStatus,
+    /// The issues found by the analysis, empty if there are none.
+    pub findings: Vec<Finding>,
+    /// The preconditions under which the result holds, empty if there are none.
+    pub assumptions: Vec<Assumption>,
+    /// The values the challenges were fixed to, `None` if the circuit has no challenge.
+    pub challenges: Option<ChallengeValues>,
+}
+
+/// The random values the challenges of a circuit were fixed to during an analysis.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct ChallengeValues {
+    /// The seed the values were drawn from, `AnalyzerConfig::seed` draws them again.
+    pub seed: u64,
+    /// The value of every challenge variable, e.g. `C-0`.
+    pub values: BTreeMap<String, String>,
+}
+
+impl fmt::Display for ChallengeValues {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let values: Vec<String> = self
+            .values
+            .iter()
+            .map(|(challenge, value)| format!("{} = {}", challenge, value))
+            .collect();
+        write!(f, "{} (seed {})", values.join(", "), self.seed)
+    }
+}
+
+/// Two witnesses that satisfy the constraints for the same public input, with every assigned cell of a copy cycle.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Counterexample {
+    pub witness: WitnessModel,
+    pub equivalent_witness: WitnessModel,
 }
 
 #[derive(Debug)]
@@ -51,4 +724,53 @@ pub enum AnalyzerType {
     UnconstrainedCells,
     UnusedColumns,
     UnderconstrainedCircuit,
+    CellDeterminism,
+    Specification,
+}
+
+/// The advice cells checked by the cell determinism analysis.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellScope {
+    /// Every advice cell assigned in a region.
+    All,
+    /// The advice cells assigned in the regions with this name.
+    Region(String),
+    /// The advice cells of the advice column with this index.
+    Column(usize),
+}
+
+/// Advice cells selected by the user, e.g. the outputs of a circuit.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CellSelector {
+    /// The advice cell of this column and row.
+    Cell { column: usize, row: usize },
+    /// The advice cells of the advice column with this index.
+    Column(usize),
+    /// The advice cells assigned in the regions with this name.
+    Region(String),
+    /// The advice cells of the columns annotated with this name in a region (pse, axiom and scroll only).
+    Annotation(String),
+}
+
+/// Whether the public input fixes the value of an advice cell.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum Determinism {
+    /// The cell has a single value for every public input that was checked.
+    Determined,
+    /// The cell can take two values for some public input.
+    Free,
+    /// The solver answered `unknown` for the given reason.
+    Unknown { reason: String },
+}
+
+/// Format of the report written by `analyzer_io::output_result`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// One sentence per analysis result, followed by the findings.
+    Text,
+    /// The versioned JSON schema of `io::report`.
+    Json,
+    /// SARIF 2.1.0, for code scanning dashboards.
+    Sarif,
 }
@@ -1,2 +1,3 @@
 pub mod analyzer_io;
 pub mod analyzer_io_type;
+pub mod report;
@@ -0,0 +1,252 @@
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::{json, Value};
+
+use super::analyzer_io_type::{
+    AnalyzerOutput, AnalyzerOutputStatus, Assumption, ChallengeValues, ConstraintOrigin,
+    Counterexample, Finding,
+};
+
+/// Version of the JSON report schema.
+///
+/// It is bumped whenever a field is removed or changes meaning, adding a field keeps the version.
+pub const REPORT_SCHEMA_VERSION: u32 = 1;
+
+const TOOL_NAME: &str = "korrekt";
+const TOOL_VERSION: &str = env!("CARGO_PKG_VERSION");
+const SARIF_VERSION: &str = "2.1.0";
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// The JSON report of an analysis.
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub schema_version: u32,
+    pub tool: &'static str,
+    pub tool_version: &'static str,
+    pub status: &'a AnalyzerOutputStatus,
+    pub findings: &'a [Finding],
+    /// The preconditions under which the status holds.
+    pub assumptions: &'a [Assumption],
+    /// The values the challenges were fixed to, absent if the circuit has no challenge.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub challenges: Option<&'a ChallengeValues>,
+}
+
+impl<'a> JsonReport<'a> {
+    pub fn new(analyzer_output: &'a AnalyzerOutput) -> Self {
+        JsonReport {
+            schema_version: REPORT_SCHEMA_VERSION,
+            tool: TOOL_NAME,
+            tool_version: TOOL_VERSION,
+            status: &analyzer_output.output_status,
+            findings: &analyzer_output.findings,
+            assumptions: &analyzer_output.assumptions,
+            challenges: analyzer_output.challenges.as_ref(),
+        }
+    }
+}
+
+/// A SARIF rule, i.e. a kind of finding.
+struct Rule {
+    id: &'static str,
+    description: &'static str,
+    level: &'static str,
+}
+
+const RULES: [Rule; 7] = [
+    Rule {
+        id: "unused-gate",
+        description: "A custom gate is identically zero over every region.",
+        level: "warning",
+    },
+    Rule {
+        id: "unused-column",
+        description: "An advice column query does not occur in any gate.",
+        level: "note",
+    },
+    Rule {
+        id: "unconstrained-cell",
+        description: "An assigned cell does not occur in any gate enabled in its region.",
+        level: "error",
+    },
+    Rule {
+        id: "underconstrained",
+        description: "Two different witnesses satisfy the constraints for the same public input.",
+        level: "error",
+    },
+    Rule {
+        id: "free-cell",
+        description: "An advice cell can take two values for the same public input.",
+        level: "error",
+    },
+    Rule {
+        id: "overconstrained",
+        description: "No witness satisfies the constraints for the public input.",
+        level: "error",
+    },
+    Rule {
+        id: "specification-violation",
+        description: "A witness satisfies the constraints but not the specification.",
+        level: "error",
+    },
+];
+
+fn rule_index(finding: &Finding) -> usize {
+    match finding {
+        Finding::UnusedGate { .. } => 0,
+        Finding::UnusedColumn { .. } => 1,
+        Finding::UnconstrainedCell { .. } => 2,
+        Finding::Underconstrained { .. } => 3,
+        Finding::FreeCell { .. } => 4,
+        Finding::Overconstrained { .. } => 5,
+        Finding::SpecificationViolation { .. } => 6,
+    }
+}
+
+/// Name of the circuit element a finding refers to.
+///
+/// It does not depend on the witness values, so it also identifies the finding across runs.
+fn logical_location(finding: &Finding) -> String {
+    match finding {
+        Finding::UnusedGate {
+            gate_index,
+            gate_name,
+        } => format!("gate[{}]:{}", gate_index, gate_name),
+        Finding::UnusedColumn { column, rotation } => format!(
+            "{:?}[{}]/rotation[{}]",
+            column.column_type, column.index, rotation
+        ),
+        Finding::UnconstrainedCell {
+            region_index,
+            region_name,
+            column,
+            row,
+            ..
+        } => format!(
+            "region[{}]:{}/{:?}[{}]/row[{}]",
+            region_index, region_name, column.column_type, column.index, row
+        ),
+        Finding::Underconstrained {
+            differing_cells, ..
+        } => {
+            // The rows of the differing cells depend on the witnesses the solver found, their columns do not.
+            let mut columns: Vec<String> = differing_cells
+                .iter()
+                .map(|cell| {
+                    format!(
+                        "region:{}/{:?}[{}]",
+                        cell.region_name.as_deref().unwrap_or_default(),
+                        cell.column.column_type,
+                        cell.column.index
+                    )
+                })
+                .collect();
+            columns.sort();
+            columns.dedup();
+            format!("circuit/{}", columns.join(","))
+        }
+        Finding::FreeCell { cell } => format!(
+            "region:{}/{:?}[{}]/row[{}]",
+            cell.region_name.as_deref().unwrap_or_default(),
+            cell.column.column_type,
+            cell.column.index,
+            cell.row
+        ),
+        Finding::Overconstrained {
+            conflicting_constraints,
+        } => {
+            let constraints: Vec<String> = conflicting_constraints
+                .iter()
+                .map(ConstraintOrigin::to_string)
+                .collect();
+            format!("circuit/{}", constraints.join(","))
+        }
+        Finding::SpecificationViolation { specification, .. } => {
+            format!("specification/{}", specification)
+        }
+    }
+}
+
+/// Builds the SARIF 2.1.0 log of an analysis.
+///
+/// Circuits have no source locations, so every result is located with a logical location
+/// (gate, column or cell) which is also used as its partial fingerprint.
+pub fn sarif_log(analyzer_output: &AnalyzerOutput) -> Value {
+    let rules: Vec<Value> = RULES
+        .iter()
+        .map(|rule| {
+            json!({
+                "id": rule.id,
+                "shortDescription": { "text": rule.description },
+                "defaultConfiguration": { "level": rule.level },
+            })
+        })
+        .collect();
+    let results: Vec<Value> = analyzer_output
+        .findings
+        .iter()
+        .map(|finding| {
+            let index = rule_index(finding);
+            let location = logical_location(finding);
+            json!({
+                "ruleId": RULES[index].id,
+                "ruleIndex": index,
+                "level": RULES[index].level,
+                "message": { "text": finding.to_string().trim_end() },
+                "locations": [{
+                    "logicalLocations": [{ "fullyQualifiedName": location }],
+                }],
+                "partialFingerprints": { "korrektLocation/v1": location },
+                "properties": { "finding": finding },
+            })
+        })
+        .collect();
+    json!({
+        "$schema": SARIF_SCHEMA,
+        "version": SARIF_VERSION,
+        "runs": [{
+            "tool": {
+                "driver": {
+                    "name": TOOL_NAME,
+                    "version": TOOL_VERSION,
+                    "rules": rules,
+                },
+            },
+            "results": results,
+            "properties": {
+                "status": &analyzer_output.output_status,
+                "assumptions": &analyzer_output.assumptions,
+                "challenges": &analyzer_output.challenges,
+            },
+        }],
+    })
+}
+
+/// The JSON export of the counterexamples of an analysis, shares the versioning of `JsonReport`.
+#[derive(Debug, Serialize)]
+pub struct CounterexampleReport<'a> {
+    pub schema_version: u32,
+    pub tool: &'static str,
+    pub tool_version: &'static str,
+    pub counterexamples: &'a [Counterexample],
+}
+
+pub fn to_json(analyzer_output: &AnalyzerOutput) -> Result<String> {
+    serde_json::to_string_pretty(&JsonReport::new(analyzer_output))
+        .context("Failed to serialize the JSON report!")
+}
+
+pub fn to_sarif(analyzer_output: &AnalyzerOutput) -> Result<String> {
+    serde_json::to_string_pretty(&sarif_log(analyzer_output))
+        .context("Failed to serialize the SARIF report!")
+}
+
+pub fn counterexamples_to_json(counterexamples: &[Counterexample]) -> Result<String> {
+    serde_json::to_string_pretty(&CounterexampleReport {
+        schema_version: REPORT_SCHEMA_VERSION,
+        tool: TOOL_NAME,
+        tool_version: TOOL_VERSION,
+        counterexamples,
+    })
+    .context("Failed to serialize the counterexamples!")
+}
@@ -1,48 +1,6 @@
-#[cfg(feature = "use_axiom_halo2_proofs")]
-use korrekt::sample_circuits::axiom as sample_circuits;
-#[cfg(feature = "use_pse_halo2_proofs")]
-use korrekt::sample_circuits::pse as sample_circuits;
-#[cfg(feature = "use_pse_v1_halo2_proofs")]
-use korrekt::sample_circuits::pse_v1 as sample_circuits;
-#[cfg(feature = "use_scroll_halo2_proofs")]
-use korrekt::sample_circuits::scroll as sample_circuits;
-#[cfg(feature = "use_zcash_halo2_proofs")]
-use korrekt::sample_circuits::zcash as sample_circuits;
-use std::marker::PhantomData;
+use anyhow::Result;
+use korrekt::{circuit_analyzer::registry::CircuitRegistry, cli};
 
-use crate::circuit_analyzer::halo2_proofs_libs::*;
-
-use anyhow::{Context, Ok, Result};
-use korrekt::{
-    circuit_analyzer::{self, analyzer::Analyzer},
-    io,
-};
-use num::{BigInt, Num};
-extern crate env_logger;
-extern crate log;
-
-use env_logger::Env;
-fn main() -> Result<(), anyhow::Error> {
-    env_logger::Builder::from_env(Env::default().default_filter_or("info")).init();
-
-    let circuit =
-        sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
-    let k = 6;
-
-    let mut analyzer = Analyzer::new(&circuit, k).unwrap();
-
-    let modulus = bn256::fr::MODULUS_STR;
-    let without_prefix = modulus.trim_start_matches("0x");
-    let prime = BigInt::from_str_radix(without_prefix, 16)
-        .unwrap()
-        .to_string();
-
-    let analyzer_type = io::analyzer_io::retrieve_user_input_for_analyzer_type()
-        .context("Failed to retrieve the user inputs!")?;
-
-    analyzer
-        .dispatch_analysis(analyzer_type, &prime)
-        .context("Failed to perform analysis!")?;
-    //println!(": {:?}", analyzer.fixed);
-    Ok(())
+fn main() -> Result<()> {
+    cli::run(&CircuitRegistry::with_sample_circuits())
 }
@@ -0,0 +1,125 @@
+use group::ff::PrimeField;
+use pse_halo2_proofs::circuit::*;
+use pse_halo2_proofs::plonk::*;
+use pse_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+/// `RlcCircuit` computes the random linear combination of two bits with a challenge `c`
+/// that is drawn after the first phase is committed. The combination is assigned in a
+/// second phase advice column and exposed as the public input.
+///
+/// |   Row   | a (1st) | b (1st) |  r (2nd)  |  i  |    s     |
+/// |---------|---------|---------|-----------|-----|----------|
+/// |   0     |    a    |    b    |  a + c*b  |  r  |    1     |
+///
+/// Gate: a_binary_check: s*a*(1-a)
+/// Gate: b_binary_check: s*b*(1-b)
+/// Gate:            rlc: s*(a+c*b-r)
+#[derive(Default)]
+pub struct RlcCircuit<F>(pub PhantomData<F>);
+
+/// `RlcCircuitUnderConstrained` misses the binarity check of `b`, so for any challenge
+/// both `a = 0` and `a = 1` lead to a valid `b` for the same public input.
+///
+/// Gate: a_binary_check: s*a*(1-a)
+/// Gate:            rlc: s*(a+c*b-r)
+#[derive(Default)]
+pub struct RlcCircuitUnderConstrained<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct RlcConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    r: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+    c: Challenge,
+}
+
+impl RlcConfig {
+    fn configure<F: PrimeField>(meta: &mut ConstraintSystem<F>, check_b: bool) -> Self {
+        let a = meta.advice_column_in(FirstPhase);
+        let b = meta.advice_column_in(FirstPhase);
+        let c = meta.challenge_usable_after(FirstPhase);
+        let r = meta.advice_column_in(SecondPhase);
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(r);
+        meta.enable_equality(i);
+
+        meta.create_gate("a_binary_check", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            vec![s * a.clone() * (Expression::Constant(F::ONE) - a)]
+        });
+        if check_b {
+            meta.create_gate("b_binary_check", |meta| {
+                let s = meta.query_selector(s);
+                let b = meta.query_advice(b, Rotation::cur());
+                vec![s * b.clone() * (Expression::Constant(F::ONE) - b)]
+            });
+        }
+        meta.create_gate("rlc", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            let r = meta.query_advice(r, Rotation::cur());
+            let c = meta.query_challenge(c);
+            vec![s * (a + c * b - r)]
+        });
+
+        RlcConfig { a, b, r, i, s, c }
+    }
+
+    fn synthesize<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        let a = F::ONE;
+        let b = F::ONE;
+        let c = layouter.get_challenge(self.c);
+        let out = layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                self.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "a", self.a, 0, || Value::known(a))?;
+                region.assign_advice(|| "b", self.b, 0, || Value::known(b))?;
+                region.assign_advice(|| "r", self.r, 0, || c.map(|c| a + c * b))
+            },
+        )?;
+        layouter.constrain_instance(out.cell(), self.i, 0)?;
+        Ok(())
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RlcCircuit<F> {
+    type Config = RlcConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RlcConfig::configure(meta, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RlcCircuitUnderConstrained<F> {
+    type Config = RlcConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RlcConfig::configure(meta, false)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
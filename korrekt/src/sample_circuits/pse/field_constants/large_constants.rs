@@ -0,0 +1,86 @@
+use group::ff::PrimeField;
+use pse_halo2_proofs::circuit::*;
+use pse_halo2_proofs::plonk::*;
+use pse_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+/// `LargeConstantsCircuit` uses a constant and a fixed value that do not fit in 64 bits:
+/// the field element `-1` as a gate constant and `-2` as a fixed cell.
+///
+/// |   Row   |   a    |   b    |   c    |   f    |  i  |    s     |
+/// |---------|--------|--------|--------|--------|-----|----------|
+/// |   0     |   a    |   b    |   c    |   -2   |  c  |    1     |
+///
+/// Gate: neg_one:      s*(-1*a+b)
+/// Gate: fixed_offset: s*(b+f-c)
+#[derive(Default)]
+pub struct LargeConstantsCircuit<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct LargeConstantsConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    c: Column<Advice>,
+    f: Column<Fixed>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: PrimeField> Circuit<F> for LargeConstantsCircuit<F> {
+    type Config = LargeConstantsConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let c = meta.advice_column();
+        let f = meta.fixed_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(c);
+        meta.enable_equality(i);
+
+        meta.create_gate("neg_one", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            // b = a, written with the constant p - 1.
+            vec![s * (a * Expression::Constant(-F::ONE) + b)]
+        });
+        meta.create_gate("fixed_offset", |meta| {
+            let s = meta.query_selector(s);
+            let b = meta.query_advice(b, Rotation::cur());
+            let c = meta.query_advice(c, Rotation::cur());
+            let f = meta.query_fixed(f, Rotation::cur());
+            // c = b - 2, with -2 stored in a fixed cell.
+            vec![s * (b + f - c)]
+        });
+
+        LargeConstantsConfig { a, b, c, f, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let out = layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let a = F::from(5);
+                region.assign_advice(|| "a", config.a, 0, || Value::known(a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(a))?;
+                region.assign_fixed(|| "f", config.f, 0, || Value::known(-F::from(2)))?;
+                region.assign_advice(|| "c", config.c, 0, || Value::known(a - F::from(2)))
+            },
+        )?;
+        layouter.constrain_instance(out.cell(), config.i, 0)?;
+        Ok(())
+    }
+}
@@ -0,0 +1 @@
+pub mod large_constants;
@@ -0,0 +1,186 @@
+use group::ff::PrimeField;
+use pse_halo2_proofs::circuit::*;
+use pse_halo2_proofs::plonk::*;
+use pse_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+/// `InstanceGateCircuit` reads its public input directly in a gate instead of
+/// copying it into an advice cell, so the instance cell is not part of any copy constraint.
+///
+/// |   Row   |   a    |   b    |  i  |    s     |
+/// |---------|--------|--------|-----|----------|
+/// |   0     |   a    |  a*a   | 2*a |    1     |
+///
+/// Gate: double: s*(2*a-i)
+/// Gate: square: s*(a*a-b)
+#[derive(Default)]
+pub struct InstanceGateCircuit<F>(pub PhantomData<F>);
+
+/// `InstanceGateCircuitUnderConstrained` only checks that `a` is a square root of the public input,
+/// so both `a` and `-a` are valid witnesses.
+///
+/// |   Row   |   a    |  i  |    s     |
+/// |---------|--------|-----|----------|
+/// |   0     |   a    | a*a |    1     |
+///
+/// Gate: square: s*(a*a-i)
+#[derive(Default)]
+pub struct InstanceGateCircuitUnderConstrained<F>(pub PhantomData<F>);
+
+/// `InstanceGateCircuitTwoRows` lays out the gates of `InstanceGateCircuit` over two rows of a region,
+/// with the selector only enabled on the first one.
+///
+/// |   Row   |   a    |   b    |  i  |    s     |
+/// |---------|--------|--------|-----|----------|
+/// |   0     |   a    |        | 2*a |    1     |
+/// |   1     |        |  a*a   |     |    0     |
+///
+/// Gate: double: s*(2*a-i)
+/// Gate: square: s*(a*a-b[next])
+#[derive(Default)]
+pub struct InstanceGateCircuitTwoRows<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct InstanceGateConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: PrimeField> Circuit<F> for InstanceGateCircuit<F> {
+    type Config = InstanceGateConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("double", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            vec![s * (Expression::Scaled(Box::new(a), F::from(2)) - i)]
+        });
+        meta.create_gate("square", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::cur());
+            vec![s * (a.clone() * a - b)]
+        });
+
+        InstanceGateConfig { a, b, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let a = F::from(3);
+                region.assign_advice(|| "a", config.a, 0, || Value::known(a))?;
+                region.assign_advice(|| "b", config.b, 0, || Value::known(a * a))?;
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for InstanceGateCircuitUnderConstrained<F> {
+    type Config = InstanceGateConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("square", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            vec![s * (a.clone() * a - i)]
+        });
+
+        InstanceGateConfig { a, b, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.name_column(|| "a", config.a);
+                region.assign_advice(|| "a", config.a, 0, || Value::known(F::from(3)))?;
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for InstanceGateCircuitTwoRows<F> {
+    type Config = InstanceGateConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("double", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            vec![s * (Expression::Scaled(Box::new(a), F::from(2)) - i)]
+        });
+        meta.create_gate("square", |meta| {
+            let s = meta.query_selector(s);
+            let a = meta.query_advice(a, Rotation::cur());
+            let b = meta.query_advice(b, Rotation::next());
+            vec![s * (a.clone() * a - b)]
+        });
+
+        InstanceGateConfig { a, b, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                let a = F::from(3);
+                region.assign_advice(|| "a", config.a, 0, || Value::known(a))?;
+                region.assign_advice(|| "b", config.b, 1, || Value::known(a * a))?;
+                Ok(())
+            },
+        )
+    }
+}
@@ -0,0 +1,172 @@
+use group::ff::PrimeField;
+use pse_halo2_proofs::circuit::*;
+use pse_halo2_proofs::plonk::*;
+use pse_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+const TABLE_ROWS: usize = 4;
+
+/// `DynamicLookupCircuit` looks up a public key and a witness value in a table whose values are
+/// advice cells copied from the public inputs, gated by the complex selector `q_t`. The keys of the
+/// table are distinct fixed values, so the looked up value is determined by the public inputs.
+///
+/// |   Row   |  key   |  val   |   tk   |   tv   |  i   | q_in | q_t |
+/// |---------|--------|--------|--------|--------|------|------|-----|
+/// |   0     |  i[4]  |  val   |   1    |  i[0]  | i[0] |  1   |  1  |
+/// |   1     |        |        |   2    |  i[1]  | i[1] |      |  1  |
+/// |   2     |        |        |   3    |  i[2]  | i[2] |      |  1  |
+/// |   3     |        |        |   4    |  i[3]  | i[3] |      |  1  |
+/// |   4     |        |        |        |        | i[4] |      |     |
+///
+/// Lookup: dynamic_lookup: (q_in*key, q_in*val) in (q_t*tk, q_t*tv)
+#[derive(Default)]
+pub struct DynamicLookupCircuit<F>(pub PhantomData<F>);
+
+/// `DynamicLookupCircuitUnderConstrained` has the same layout, but only looks up `val`, so it can
+/// be any of the values of the table.
+///
+/// Lookup: dynamic_lookup: (q_in*val) in (q_t*tv)
+#[derive(Default)]
+pub struct DynamicLookupCircuitUnderConstrained<F>(pub PhantomData<F>);
+
+/// `DynamicLookupCircuitUngatedTable` has the same layout without the selector `q_t`, so the table
+/// spans every usable row of `tk` and `tv`, but only its first four rows are assigned.
+///
+/// Lookup: dynamic_lookup: (q_in*key, q_in*val) in (tk, tv)
+#[derive(Default)]
+pub struct DynamicLookupCircuitUngatedTable<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct DynamicLookupConfig {
+    key: Column<Advice>,
+    val: Column<Advice>,
+    tk: Column<Fixed>,
+    tv: Column<Advice>,
+    i: Column<Instance>,
+    q_in: Selector,
+    q_t: Option<Selector>,
+}
+
+impl DynamicLookupConfig {
+    fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        with_key: bool,
+        gated_table: bool,
+    ) -> Self {
+        let key = meta.advice_column();
+        let val = meta.advice_column();
+        let tk = meta.fixed_column();
+        let tv = meta.advice_column();
+        let i = meta.instance_column();
+        let q_in = meta.complex_selector();
+        let q_t = gated_table.then(|| meta.complex_selector());
+
+        meta.enable_equality(key);
+        meta.enable_equality(tv);
+        meta.enable_equality(i);
+
+        meta.lookup_any("dynamic_lookup", |meta| {
+            let q_in = meta.query_selector(q_in);
+            let key = meta.query_advice(key, Rotation::cur());
+            let val = meta.query_advice(val, Rotation::cur());
+            let mut tk = meta.query_fixed(tk, Rotation::cur());
+            let mut tv = meta.query_advice(tv, Rotation::cur());
+            if let Some(q_t) = q_t {
+                let q_t = meta.query_selector(q_t);
+                tk = q_t.clone() * tk;
+                tv = q_t * tv;
+            }
+            let mut lookups = vec![];
+            if with_key {
+                lookups.push((q_in.clone() * key, tk));
+            }
+            lookups.push((q_in * val, tv));
+            lookups
+        });
+
+        DynamicLookupConfig {
+            key,
+            val,
+            tk,
+            tv,
+            i,
+            q_in,
+            q_t,
+        }
+    }
+
+    fn synthesize<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                for row in 0..TABLE_ROWS {
+                    if let Some(q_t) = self.q_t {
+                        q_t.enable(&mut region, row)?;
+                    }
+                    region.assign_fixed(
+                        || "tk",
+                        self.tk,
+                        row,
+                        || Value::known(F::from(row as u64 + 1)),
+                    )?;
+                    region.assign_advice_from_instance(|| "tv", self.i, row, self.tv, row)?;
+                }
+                self.q_in.enable(&mut region, 0)?;
+                region.assign_advice_from_instance(|| "key", self.i, TABLE_ROWS, self.key, 0)?;
+                region.assign_advice(|| "val", self.val, 0, || Value::known(F::from(30)))?;
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DynamicLookupCircuit<F> {
+    type Config = DynamicLookupConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        DynamicLookupConfig::configure(meta, true, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DynamicLookupCircuitUnderConstrained<F> {
+    type Config = DynamicLookupConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        DynamicLookupConfig::configure(meta, false, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for DynamicLookupCircuitUngatedTable<F> {
+    type Config = DynamicLookupConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        DynamicLookupConfig::configure(meta, true, false)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
@@ -1,4 +1,6 @@
+pub mod dynamic_lookup;
 pub mod lookup;
 pub mod lookup_underconstrained;
 pub mod multiple_lookups;
 pub mod multiple_matched_lookups;
+pub mod range_decomp;
@@ -1,3 +1,42 @@
 pub mod bit_decomposition;
+pub mod challenges;
 pub mod copy_constraint;
+pub mod field_constants;
+pub mod instance_query;
 pub mod lookup_circuits;
+pub mod shuffle_circuits;
+
+use crate::circuit_analyzer::{halo2_proofs_libs::Fr, registry::CircuitRegistry};
+use crate::register_circuit;
+
+/// Registers every PSE sample circuit under the name of its module.
+pub fn register_circuits(registry: &mut CircuitRegistry<Fr>) {
+    register_circuit!(registry, "add_multiplication", bit_decomposition::add_multiplication::AddMultCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp", bit_decomposition::two_bit_decomp::TwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp_underconstrained", bit_decomposition::two_bit_decomp::TwoBitDecompCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "two_bit_decomp_multirow", bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp_multirow_underconstrained", bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "two_bit_decomp_lookup", bit_decomposition::two_bit_decomp_lookup::TwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "rlc", challenges::rlc::RlcCircuit<Fr>);
+    register_circuit!(registry, "rlc_underconstrained", challenges::rlc::RlcCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "fibonacci", copy_constraint::fibonacci::FibonacciCircuit<Fr>);
+    register_circuit!(registry, "fibonacci_constant_init", copy_constraint::fibonacci_constant_init::FibonacciCircuit<Fr>);
+    register_circuit!(registry, "fibonacci_for_bench_8", copy_constraint::fibonacci_for_bench::FibonacciCircuit<Fr, 8>);
+    register_circuit!(registry, "fibonacci_for_bench_32", copy_constraint::fibonacci_for_bench::FibonacciCircuit<Fr, 32>);
+    register_circuit!(registry, "large_constants", field_constants::large_constants::LargeConstantsCircuit<Fr>);
+    register_circuit!(registry, "instance_gate", instance_query::instance_gate::InstanceGateCircuit<Fr>);
+    register_circuit!(registry, "instance_gate_underconstrained", instance_query::instance_gate::InstanceGateCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "is_zero", instance_query::is_zero::IsZeroCircuit<Fr>);
+    register_circuit!(registry, "dynamic_lookup", lookup_circuits::dynamic_lookup::DynamicLookupCircuit<Fr>);
+    register_circuit!(registry, "dynamic_lookup_underconstrained", lookup_circuits::dynamic_lookup::DynamicLookupCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "dynamic_lookup_ungated_table", lookup_circuits::dynamic_lookup::DynamicLookupCircuitUngatedTable<Fr>);
+    register_circuit!(registry, "lookup", lookup_circuits::lookup::MyCircuit<Fr>);
+    register_circuit!(registry, "lookup_underconstrained", lookup_circuits::lookup_underconstrained::MyCircuit<Fr>);
+    register_circuit!(registry, "multiple_lookups", lookup_circuits::multiple_lookups::MyCircuit<Fr>);
+    register_circuit!(registry, "multiple_matched_lookups", lookup_circuits::multiple_matched_lookups::MyCircuit<Fr>);
+    register_circuit!(registry, "range_decomp", lookup_circuits::range_decomp::RangeDecompCircuit<Fr>);
+    register_circuit!(registry, "range_decomp_underconstrained", lookup_circuits::range_decomp::RangeDecompCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "shuffle", shuffle_circuits::shuffle::ShuffleCircuit<Fr>);
+    register_circuit!(registry, "shuffle_underconstrained", shuffle_circuits::shuffle::ShuffleCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "shuffle_any_order", shuffle_circuits::shuffle::ShuffleCircuitAnyOrder<Fr>);
+}
@@ -0,0 +1,150 @@
+use group::ff::PrimeField;
+use pse_halo2_proofs::circuit::*;
+use pse_halo2_proofs::plonk::*;
+use pse_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+const ROWS: usize = 4;
+
+/// `ShuffleCircuit` copies the public inputs into `a` and requires `b` to be a shuffle of `a`.
+/// The `same` gate makes all cells of `b` equal, so the shuffle forces every public input to be
+/// equal to `b` as well, and `b` is fully determined by the public inputs.
+///
+/// |   Row   |   a    |   b    |  i   |  s  |  q  |
+/// |---------|--------|--------|------|-----|-----|
+/// |   0     |  i[0]  |   x    | i[0] |  1  |  1  |
+/// |   1     |  i[1]  |   x    | i[1] |  1  |  1  |
+/// |   2     |  i[2]  |   x    | i[2] |  1  |  1  |
+/// |   3     |  i[3]  |   x    | i[3] |     |  1  |
+///
+/// Gate:    same: s*(b-b_next)
+/// Shuffle: shuffle: q*a ~ q*b
+#[derive(Default)]
+pub struct ShuffleCircuit<F>(pub PhantomData<F>);
+
+/// `ShuffleCircuitUnderConstrained` has the same layout as `ShuffleCircuit` without the shuffle
+/// argument, so nothing relates `b` to the public inputs.
+///
+/// Gate:    same: s*(b-b_next)
+#[derive(Default)]
+pub struct ShuffleCircuitUnderConstrained<F>(pub PhantomData<F>);
+
+/// `ShuffleCircuitAnyOrder` has the same layout as `ShuffleCircuit` without the `same` gate, so `b`
+/// can be any permutation of the public inputs.
+///
+/// Shuffle: shuffle: q*a ~ q*b
+#[derive(Default)]
+pub struct ShuffleCircuitAnyOrder<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct ShuffleConfig {
+    a: Column<Advice>,
+    b: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+    q: Selector,
+}
+
+impl ShuffleConfig {
+    fn configure<F: PrimeField>(
+        meta: &mut ConstraintSystem<F>,
+        with_shuffle: bool,
+        with_same: bool,
+    ) -> Self {
+        let a = meta.advice_column();
+        let b = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+        let q = meta.complex_selector();
+
+        meta.enable_equality(a);
+        meta.enable_equality(i);
+
+        if with_same {
+            meta.create_gate("same", |meta| {
+                let s = meta.query_selector(s);
+                let b_cur = meta.query_advice(b, Rotation::cur());
+                let b_next = meta.query_advice(b, Rotation::next());
+                vec![s * (b_cur - b_next)]
+            });
+        }
+        if with_shuffle {
+            meta.shuffle("shuffle", |meta| {
+                let q = meta.query_selector(q);
+                let a = meta.query_advice(a, Rotation::cur());
+                let b = meta.query_advice(b, Rotation::cur());
+                vec![(q.clone() * a, q * b)]
+            });
+        }
+
+        ShuffleConfig { a, b, i, s, q }
+    }
+
+    fn synthesize<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                for row in 0..ROWS {
+                    if row + 1 < ROWS {
+                        self.s.enable(&mut region, row)?;
+                    }
+                    self.q.enable(&mut region, row)?;
+                    region.assign_advice_from_instance(|| "a", self.i, row, self.a, row)?;
+                    region.assign_advice(|| "b", self.b, row, || Value::known(F::from(7)))?;
+                }
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ShuffleCircuit<F> {
+    type Config = ShuffleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ShuffleConfig::configure(meta, true, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ShuffleCircuitUnderConstrained<F> {
+    type Config = ShuffleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ShuffleConfig::configure(meta, false, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for ShuffleCircuitAnyOrder<F> {
+    type Config = ShuffleConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        ShuffleConfig::configure(meta, true, false)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
@@ -1,3 +1,22 @@
 pub mod bit_decomposition;
 pub mod copy_constraint;
-pub mod lookup_circuits;
\ No newline at end of file
+pub mod lookup_circuits;
+
+use crate::circuit_analyzer::{halo2_proofs_libs::Fr, registry::CircuitRegistry};
+use crate::register_circuit;
+
+/// Registers every PSE v1 sample circuit under the name of its module.
+pub fn register_circuits(registry: &mut CircuitRegistry<Fr>) {
+    register_circuit!(registry, "add_multiplication", bit_decomposition::add_multiplication::AddMultCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp", bit_decomposition::two_bit_decomp::TwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp_underconstrained", bit_decomposition::two_bit_decomp::TwoBitDecompCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "two_bit_decomp_multirow", bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "general_bit_decomp_8", bit_decomposition::general_bit_decomp::BitDecompositon<Fr, 8>);
+    register_circuit!(registry, "general_bit_decomp_underconstrained_8", bit_decomposition::general_bit_decomp::BitDecompositonUnderConstrained<Fr, 8>);
+    register_circuit!(registry, "fibonacci", copy_constraint::fibonacci::FibonacciCircuit<Fr>);
+    register_circuit!(registry, "fibonacci_for_bench_8", copy_constraint::fibonacci_for_bench::FibonacciCircuit<Fr, 8>);
+    register_circuit!(registry, "fibonacci_for_bench_32", copy_constraint::fibonacci_for_bench::FibonacciCircuit<Fr, 32>);
+    register_circuit!(registry, "lookup", lookup_circuits::lookup::MyCircuit<Fr>);
+    register_circuit!(registry, "lookup_underconstrained", lookup_circuits::lookup_underconstrained::MyCircuit<Fr>);
+    register_circuit!(registry, "multiple_lookups", lookup_circuits::multiple_lookups::MyCircuit<Fr>);
+}
@@ -1,3 +1,20 @@
 pub mod bit_decomposition;
 pub mod copy_constraint;
 pub mod lookup_circuits;
+
+use crate::circuit_analyzer::{halo2_proofs_libs::Fr, registry::CircuitRegistry};
+use crate::register_circuit;
+
+/// Registers every Scroll sample circuit under the name of its module.
+pub fn register_circuits(registry: &mut CircuitRegistry<Fr>) {
+    register_circuit!(registry, "add_multiplication", bit_decomposition::add_multiplication::AddMultCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp", bit_decomposition::two_bit_decomp::TwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "two_bit_decomp_underconstrained", bit_decomposition::two_bit_decomp::TwoBitDecompCircuitUnderConstrained<Fr>);
+    register_circuit!(registry, "two_bit_decomp_multirow", bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuit<Fr>);
+    register_circuit!(registry, "fibonacci", copy_constraint::fibonacci::FibonacciCircuit<Fr>);
+    register_circuit!(registry, "fibonacci_constant_init", copy_constraint::fibonacci_constant_init::FibonacciCircuit<Fr>);
+    register_circuit!(registry, "lookup", lookup_circuits::lookup::MyCircuit<Fr>);
+    register_circuit!(registry, "lookup_underconstrained", lookup_circuits::lookup_underconstrained::MyCircuit<Fr>);
+    register_circuit!(registry, "multiple_lookups", lookup_circuits::multiple_lookups::MyCircuit<Fr>);
+    register_circuit!(registry, "multiple_matched_lookups", lookup_circuits::multiple_matched_lookups::MyCircuit<Fr>);
+}
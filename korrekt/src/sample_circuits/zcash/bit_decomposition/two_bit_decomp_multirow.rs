@@ -124,3 +124,95 @@ impl<F: Field> Circuit<F> for MultiRowTwoBitDecompCircuit<F> {
         Ok(())
     }
 }
+
+
+// `MultiRowTwoBitDecompCircuitUnderConstrained`: Same layout as `MultiRowTwoBitDecompCircuit`,
+// but the binarity check of `b1` is missing. Since `s` is only enabled on row 0, nothing
+// forces `b1` to be binary, so x = b0 + 2*b1 has two decompositions (b0 = 0 or b0 = 1).
+/// |   Row   | advice  |instance|    s     |      
+/// |---------|---------|--------|----------| 
+/// |   0     |   b0    |  x     |    1     | 
+/// |   1     |   b1    |        |          |
+/// |   2     |   x     |        |          |
+/// 
+///    Gate: b0_binary_check:  s*b0*(1-b0) 
+///    Gate:        equality:  s*(b0+2*b1-x)
+pub struct MultiRowTwoBitDecompCircuitUnderConstrained<F: Field> {
+    b0: F,
+    b1: F,
+}
+
+impl<F: Field> Default for MultiRowTwoBitDecompCircuitUnderConstrained<F> {
+    fn default() -> Self {
+        MultiRowTwoBitDecompCircuitUnderConstrained {
+            b0: F::ONE,
+            b1: F::ONE,
+        }
+    }
+}
+
+impl<F: Field> Circuit<F> for MultiRowTwoBitDecompCircuitUnderConstrained<F> {
+    type Config = MultiRowTwoBitDecompCircuitConfig<F>;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let x = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.enable_equality(x);
+        meta.enable_equality(i);
+
+        meta.create_gate("b0_binary_check", |meta| {
+            let a = meta.query_advice(x, Rotation::cur());
+            let dummy = meta.query_selector(s);
+            // b0 * (1-b0)
+            vec![dummy * a.clone() * (Expression::Constant(F::from(1)) - a)]
+        });
+        meta.create_gate("equality", |meta| {
+            let a = meta.query_advice(x, Rotation::cur());
+            let b = meta.query_advice(x, Rotation::next());
+            let c = meta.query_advice(x, Rotation(2));
+            let dummy = meta.query_selector(s);
+            vec![dummy * (a + Expression::Scaled(Box::new(b), F::from(2)) - c)]
+        });
+
+        Self::Config {
+            _ph: PhantomData,
+            advice: x,
+            instance: i,
+            s,
+        }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        let out = layouter.assign_region(
+            || "The Region",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+
+                region.assign_advice(|| "b0", config.advice, 0, || Value::known(self.b0))?;
+
+                region.assign_advice(|| "b1", config.advice, 1, || Value::known(self.b1))?;
+
+                region.assign_advice(
+                    || "x",
+                    config.advice,
+                    2,
+                    || Value::known(self.b0 + F::from(2) * self.b1),
+                )
+            },
+        )?;
+        // expose the public input
+        layouter.constrain_instance(out.cell(), config.instance, 0)?;
+        Ok(())
+    }
+}
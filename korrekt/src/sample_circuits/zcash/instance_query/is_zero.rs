@@ -0,0 +1,72 @@
+use group::ff::PrimeField;
+use zcash_halo2_proofs::circuit::*;
+use zcash_halo2_proofs::plonk::*;
+use zcash_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+/// `IsZeroCircuit` computes whether its public input is zero with the usual is-zero gadget.
+///
+/// The inverse of the input is a hint: it is fixed by the gates for a non-zero input, but any value
+/// is valid for a zero input. The output `out` is always determined by the public input.
+///
+/// |   Row   |   inv   |  out   |  i  |    s     |
+/// |---------|---------|--------|-----|----------|
+/// |   0     |  1 / i  | i == 0 |  i  |    1     |
+///
+/// Gate: is zero: s*(out-(1-i*inv)), s*(i*out)
+#[derive(Default)]
+pub struct IsZeroCircuit<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct IsZeroConfig {
+    inv: Column<Advice>,
+    out: Column<Advice>,
+    i: Column<Instance>,
+    s: Selector,
+}
+
+impl<F: PrimeField> Circuit<F> for IsZeroCircuit<F> {
+    type Config = IsZeroConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        let inv = meta.advice_column();
+        let out = meta.advice_column();
+        let i = meta.instance_column();
+        let s = meta.selector();
+
+        meta.create_gate("is zero", |meta| {
+            let s = meta.query_selector(s);
+            let inv = meta.query_advice(inv, Rotation::cur());
+            let out = meta.query_advice(out, Rotation::cur());
+            let i = meta.query_instance(i, Rotation::cur());
+            let one = Expression::Constant(F::from(1));
+            vec![
+                s.clone() * (out.clone() - (one - i.clone() * inv)),
+                s * (i * out),
+            ]
+        });
+
+        IsZeroConfig { inv, out, i, s }
+    }
+
+    fn synthesize(
+        &self,
+        config: Self::Config,
+        mut layouter: impl Layouter<F>,
+    ) -> Result<(), Error> {
+        layouter.assign_region(
+            || "Is Zero",
+            |mut region| {
+                config.s.enable(&mut region, 0)?;
+                region.assign_advice(|| "inv", config.inv, 0, || Value::known(F::from(0)))?;
+                region.assign_advice(|| "out", config.out, 0, || Value::known(F::from(1)))?;
+                Ok(())
+            },
+        )
+    }
+}
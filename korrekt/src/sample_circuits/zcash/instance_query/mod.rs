@@ -0,0 +1,2 @@
+pub mod instance_gate;
+pub mod is_zero;
@@ -2,3 +2,4 @@ pub mod lookup_underconstrained;
 pub mod multiple_lookups;
 pub mod lookup;
 pub mod multiple_matched_lookups;
+pub mod range_decomp;
@@ -0,0 +1,124 @@
+use group::ff::PrimeField;
+use zcash_halo2_proofs::circuit::*;
+use zcash_halo2_proofs::plonk::*;
+use zcash_halo2_proofs::poly::Rotation;
+use std::marker::PhantomData;
+
+/// `RangeDecompCircuit` splits its public input `x < 64` into a high and a low 3-bit limb.
+/// The low limb is never assigned to a cell: the lookup input is the polynomial `q*(x - 8*hi)`.
+///
+/// |   Row   |   x    |   hi   |  i  |    q     | range  |
+/// |---------|--------|--------|-----|----------|--------|
+/// |   0     |   x    |  x/8   |  x  |    1     |   0    |
+/// |  ...    |        |        |     |          |  ...   |
+/// |   7     |        |        |     |          |   7    |
+///
+/// Lookup: q*(x - 8*hi) in range
+/// Lookup: q*hi in range
+#[derive(Default)]
+pub struct RangeDecompCircuit<F>(pub PhantomData<F>);
+
+/// `RangeDecompCircuitUnderConstrained` does not range check the high limb,
+/// so every `hi` with `x - 8*hi` in `0..8` is a valid witness.
+///
+/// Lookup: q*(x - 8*hi) in range
+#[derive(Default)]
+pub struct RangeDecompCircuitUnderConstrained<F>(pub PhantomData<F>);
+
+#[derive(Clone)]
+pub struct RangeDecompConfig {
+    x: Column<Advice>,
+    hi: Column<Advice>,
+    i: Column<Instance>,
+    q: Selector,
+    range: TableColumn,
+}
+
+impl RangeDecompConfig {
+    fn configure<F: PrimeField>(meta: &mut ConstraintSystem<F>, check_hi: bool) -> Self {
+        let x = meta.advice_column();
+        let hi = meta.advice_column();
+        let i = meta.instance_column();
+        let q = meta.complex_selector();
+        let range = meta.lookup_table_column();
+
+        meta.enable_equality(x);
+        meta.enable_equality(i);
+
+        meta.lookup(|meta| {
+            let q = meta.query_selector(q);
+            let x = meta.query_advice(x, Rotation::cur());
+            let hi = meta.query_advice(hi, Rotation::cur());
+            vec![(q * (x - Expression::Constant(F::from(8)) * hi), range)]
+        });
+        if check_hi {
+            meta.lookup(|meta| {
+                let q = meta.query_selector(q);
+                let hi = meta.query_advice(hi, Rotation::cur());
+                vec![(q * hi, range)]
+            });
+        }
+
+        RangeDecompConfig { x, hi, i, q, range }
+    }
+
+    fn synthesize<F: PrimeField>(&self, mut layouter: impl Layouter<F>) -> Result<(), Error> {
+        layouter.assign_table(
+            || "range",
+            |mut table| {
+                for value in 0..8 {
+                    table.assign_cell(
+                        || "range",
+                        self.range,
+                        value,
+                        || Value::known(F::from(value as u64)),
+                    )?;
+                }
+                Ok(())
+            },
+        )?;
+        layouter.assign_region(
+            || "decomposition",
+            |mut region| {
+                self.q.enable(&mut region, 0)?;
+                region.assign_advice_from_instance(|| "x", self.i, 0, self.x, 0)?;
+                region.assign_advice(|| "hi", self.hi, 0, || Value::known(F::from(5)))?;
+                Ok(())
+            },
+        )
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RangeDecompCircuit<F> {
+    type Config = RangeDecompConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RangeDecompConfig::configure(meta, true)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
+
+impl<F: PrimeField> Circuit<F> for RangeDecompCircuitUnderConstrained<F> {
+    type Config = RangeDecompConfig;
+    type FloorPlanner = SimpleFloorPlanner;
+
+    fn without_witnesses(&self) -> Self {
+        Self::default()
+    }
+
+    fn configure(meta: &mut ConstraintSystem<F>) -> Self::Config {
+        RangeDecompConfig::configure(meta, false)
+    }
+
+    fn synthesize(&self, config: Self::Config, layouter: impl Layouter<F>) -> Result<(), Error> {
+        config.synthesize(layouter)
+    }
+}
@@ -0,0 +1,72 @@
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::io::analyzer_io_type::AnalyzerConfig;
+
+/// Number of runs started by this process, it tells apart the files of concurrent runs.
+static RUN_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+/// The SMT files written during one run of the under-constrained analysis.
+///
+/// Every run gets its own file names, so several analyses (e.g. parallel tests) can share the
+/// output directory. Unless `keep_artifacts` is set, the files are deleted when this is dropped,
+/// whether the analysis succeeded or not.
+#[derive(Debug)]
+pub struct SmtArtifacts {
+    smt_file_path: PathBuf,
+    script_path: PathBuf,
+    keep: bool,
+}
+
+impl SmtArtifacts {
+    pub fn new(config: &AnalyzerConfig) -> Result<Self> {
+        let output_dir = config
+            .output_dir
+            .clone()
+            .unwrap_or_else(|| std::env::temp_dir().join("korrekt"));
+        fs::create_dir_all(&output_dir).with_context(|| {
+            format!(
+                "Failed to create the output directory {}!",
+                output_dir.display()
+            )
+        })?;
+        let nanos = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.subsec_nanos())
+            .unwrap_or_default();
+        let stem = format!(
+            "out-{}-{}-{}",
+            process::id(),
+            RUN_COUNTER.fetch_add(1, Ordering::Relaxed),
+            nanos
+        );
+        Ok(SmtArtifacts {
+            smt_file_path: output_dir.join(format!("{}.smt2", stem)),
+            script_path: output_dir.join(format!("{}_temp.smt2", stem)),
+            keep: config.keep_artifacts,
+        })
+    }
+
+    /// The SMT file written by the analyzer.
+    pub fn smt_file_path(&self) -> &Path {
+        &self.smt_file_path
+    }
+
+    /// The query solved from scratch when the solver runs on files.
+    pub fn script_path(&self) -> &Path {
+        &self.script_path
+    }
+}
+
+impl Drop for SmtArtifacts {
+    fn drop(&mut self) {
+        if !self.keep {
+            let _ = fs::remove_file(&self.smt_file_path);
+            let _ = fs::remove_file(&self.script_path);
+        }
+    }
+}
@@ -1,2 +1,4 @@
+pub mod artifacts;
 pub mod smt;
 pub mod smt_parser;
+pub mod solver;
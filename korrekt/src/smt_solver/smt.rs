@@ -1,17 +1,41 @@
 use anyhow::{anyhow, Result};
-use std::collections::HashMap;
+use num::{BigInt, BigUint, Integer};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
 use std::fs::File;
 use std::io::Write;
 
 use crate::circuit_analyzer::analyzer::{self, NodeType};
+use crate::io::analyzer_io_type::{ConstraintOrigin, FieldEncoding, SolverKind};
 
 pub struct Printer<'a, W: 'a> {
     writer: &'a mut W,
     pub vars: HashMap<String, bool>,
+    encoding: FieldEncoding,
+    prime: BigUint,
+    /// Whether assertions are named, so that the solver can report them in unsat cores.
+    unsat_cores: bool,
+    /// The constraint of the circuit the assertions being written come from, see `set_origin`.
+    origin: Option<ConstraintOrigin>,
+    /// The constraint each named assertion comes from, keyed by the name of the assertion.
+    pub named_assertions: HashMap<String, ConstraintOrigin>,
 }
 
-fn get_logic_string() -> String {
-    String::from("ALL")
+/// Whether the solver can report the named assertions of an unsatisfiable query.
+///
+/// Yices computes unsat cores with its default solver only, not with MCSAT, which the finite field theory needs.
+fn supports_unsat_cores(solver: SolverKind) -> bool {
+    !matches!(solver, SolverKind::Yices)
+}
+
+fn get_logic_string(solver: SolverKind, encoding: FieldEncoding) -> String {
+    match (solver, encoding) {
+        (SolverKind::Yices, FieldEncoding::FiniteField) => String::from("QF_FFA"),
+        (SolverKind::Yices, FieldEncoding::Integer) => String::from("QF_UFNIA"),
+        (SolverKind::Yices, FieldEncoding::BitVector) => String::from("QF_UFBV"),
+        (SolverKind::Bitwuzla, _) => String::from("QF_UFBV"),
+        _ => String::from("ALL"),
+    }
 }
 
 impl<'a, W: 'a + Write> Printer<'a, W> {
@@ -19,6 +43,166 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         Self {
             writer,
             vars: HashMap::new(),
+            encoding: FieldEncoding::FiniteField,
+            prime: BigUint::default(),
+            unsat_cores: false,
+            origin: None,
+            named_assertions: HashMap::new(),
+        }
+    }
+    /// Sets the constraint of the circuit the next assertions come from, `None` once they no longer come from one.
+    ///
+    /// While it is set, the assertions are named and the name is mapped to `origin` in `named_assertions`,
+    /// so that an unsat core can be traced back to the circuit.
+    pub fn set_origin(&mut self, origin: Option<ConstraintOrigin>) {
+        self.origin = origin;
+    }
+    /// Writes a second copy of the variables and assertions of `commands`, the SMT-LIB commands written so far.
+    ///
+    /// Every symbol of `renamed` is renamed to its name followed by `suffix` in the copy, the other variables
+    /// (e.g. the instance cells) are shared by both copies. Functions are shared as well, so only the declarations
+    /// of the renamed symbols and the assertions that mention them are copied. Named assertions are named after
+    /// the name of the original followed by `suffix`, and come from the same constraint. Only the copies of field
+    /// variables are added to `vars`.
+    pub fn write_renamed_copy(&mut self, commands: &str, renamed: &HashSet<String>, suffix: &str) {
+        let token = Regex::new(r"[^\s()]+").unwrap();
+        let mut depth = 0;
+        let mut start = 0;
+        for (index, c) in commands.char_indices() {
+            match c {
+                '(' => {
+                    if depth == 0 {
+                        start = index;
+                    }
+                    depth += 1;
+                }
+                ')' => {
+                    depth -= 1;
+                    if depth > 0 {
+                        continue;
+                    }
+                    let command = &commands[start..=index];
+                    let is_assertion = command.starts_with("(assert");
+                    if !is_assertion && !command.starts_with("(declare-fun") {
+                        continue;
+                    }
+                    let mut names = vec![];
+                    let mut variables = vec![];
+                    let copy = token.replace_all(command, |captures: &regex::Captures| {
+                        let name = &captures[0];
+                        if renamed.contains(name) {
+                            variables.push(name.to_owned());
+                            format!("{}{}", name, suffix)
+                        } else if self.named_assertions.contains_key(name) {
+                            names.push(name.to_owned());
+                            format!("{}{}", name, suffix)
+                        } else {
+                            name.to_owned()
+                        }
+                    });
+                    // Declarations of functions and assertions over shared variables only are not copied.
+                    if variables.is_empty() {
+                        continue;
+                    }
+                    writeln!(&mut self.writer, "{}", copy).unwrap();
+                    for name in names {
+                        let origin = self.named_assertions[&name].clone();
+                        self.named_assertions
+                            .insert(format!("{}{}", name, suffix), origin);
+                    }
+                    if !is_assertion {
+                        for name in variables {
+                            if self.vars.contains_key(&name) {
+                                self.vars.insert(format!("{}{}", name, suffix), true);
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+    /// Writes `(assert term)`, with `term` named after the current origin if there is one.
+    fn write_assertion(&mut self, term: &str) {
+        match &self.origin {
+            Some(origin) if self.unsat_cores => {
+                let name = format!("N-{}", self.named_assertions.len());
+                writeln!(&mut self.writer, "(assert (! {} :named {}))", term, name).unwrap();
+                self.named_assertions.insert(name, origin.clone());
+            }
+            _ => writeln!(&mut self.writer, "(assert {})", term).unwrap(),
+        }
+    }
+    /// Asserts that `var` is less than 2^`bits`.
+    ///
+    /// The finite field theory has no order, so `var` is decomposed into `bits` boolean variables `B-{var}-{i}`
+    /// instead. Nothing is written if every field element is less than 2^`bits`.
+    fn write_bit_length(&mut self, var: &str, bits: u32) {
+        if u64::from(bits) >= self.bit_width() {
+            return;
+        }
+        let bound = BigUint::from(1u8) << bits;
+        match self.encoding {
+            FieldEncoding::Integer => self.write_assertion(&format!("(< {} {})", var, bound)),
+            FieldEncoding::BitVector => self.write_assertion(&format!(
+                "(bvult {} (_ bv{} {}))",
+                var,
+                bound,
+                self.bit_width()
+            )),
+            FieldEncoding::FiniteField => {
+                let (zero, one) = (self.get_constant("0"), self.get_constant("1"));
+                let mut terms = vec![];
+                for i in 0..bits {
+                    let bit = format!("B-{}-{}", var, i);
+                    self.write_var(bit.clone());
+                    self.write_assertion(&format!("(or (= {} {}) (= {} {}))", bit, zero, bit, one));
+                    let weight = self.get_constant(&(BigUint::from(1u8) << i).to_string());
+                    terms.push(format!("(ff.mul {} {})", weight, bit));
+                }
+                let sum = match terms.len() {
+                    0 => zero,
+                    1 => terms.remove(0),
+                    _ => format!("(ff.add {})", terms.join(" ")),
+                };
+                self.write_assertion(&format!("(= {} {})", var, sum));
+            }
+        }
+    }
+    /// Number of bits of the bit-vectors encoding field elements.
+    fn bit_width(&self) -> u64 {
+        self.prime.bits()
+    }
+    /// Returns the SMT-LIB constant for the field element `value`, given in decimal and possibly negative.
+    pub fn get_constant(&self, value: &str) -> String {
+        match self.encoding {
+            FieldEncoding::FiniteField => format!("(as ff{} F)", value),
+            FieldEncoding::Integer | FieldEncoding::BitVector => {
+                let prime = BigInt::from(self.prime.clone());
+                let reduced = value
+                    .trim()
+                    .parse::<BigInt>()
+                    .unwrap()
+                    .mod_floor(&prime);
+                if matches!(self.encoding, FieldEncoding::Integer) {
+                    reduced.to_string()
+                } else {
+                    format!("(_ bv{} {})", reduced, self.bit_width())
+                }
+            }
+        }
+    }
+    /// Returns the negation of `term`, without the enclosing parentheses like `write_term`.
+    ///
+    /// `term` must be an atom or parenthesized.
+    pub fn get_neg(&self, term: String) -> String {
+        match self.encoding {
+            FieldEncoding::FiniteField => format!("ff.neg {}", term),
+            FieldEncoding::Integer => format!("mod (- {}) {}", term, self.prime),
+            FieldEncoding::BitVector => {
+                let prime = format!("(_ bv{} {})", self.prime, self.bit_width());
+                format!("bvurem (bvsub {} {}) {}", prime, term, prime)
+            }
         }
     }
     /// Constructs a term string based on the provided operator and operands.
@@ -53,27 +237,69 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         } else {
             format!("({})", right)
         };
-        let t = format!("ff.{} {} {}", op, l, r);
-        t
+        match self.encoding {
+            FieldEncoding::FiniteField => format!("ff.{} {} {}", op, l, r),
+            FieldEncoding::Integer => {
+                let int_op = if op == "mul" { "*" } else { "+" };
+                format!("mod ({} {} {}) {}", int_op, l, r, self.prime)
+            }
+            FieldEncoding::BitVector => {
+                // The operands are zero-extended so that the sum or product does not overflow before the reduction.
+                let n = self.bit_width();
+                let (bv_op, extension) = if op == "mul" { ("bvmul", n) } else { ("bvadd", 1) };
+                format!(
+                    "(_ extract {} 0) (bvurem ({} ((_ zero_extend {}) {}) ((_ zero_extend {}) {})) (_ bv{} {}))",
+                    n - 1,
+                    bv_op,
+                    extension,
+                    l,
+                    extension,
+                    r,
+                    self.prime,
+                    n + extension
+                )
+            }
+        }
     }
     /// Writes the start of the SMT-LIB file.
     ///
     /// This function writes the initial lines at the start of the SMT-LIB file,
-    /// including the SMT-LIB version, category, options, logic, and the definition of the finite field.
+    /// including the SMT-LIB version, category, options, logic, and the definition of the sort `F` of field elements.
+    /// The assertions are only named if `unsat_cores` is set and the solver supports unsat cores.
     ///
-    fn write_start(&mut self, prime: String) {
+    fn write_start(
+        &mut self,
+        prime: String,
+        encoding: FieldEncoding,
+        solver: SolverKind,
+        unsat_cores: bool,
+    ) {
+        self.prime = prime.parse().unwrap();
+        self.encoding = encoding;
+        self.unsat_cores = unsat_cores && supports_unsat_cores(solver);
         writeln!(&mut self.writer, "(set-info :smt-lib-version 2.6)").unwrap();
         writeln!(&mut self.writer, "(set-info :category \"crafted\")").unwrap();
         writeln!(&mut self.writer, "(set-option :produce-models true)").unwrap();
-        writeln!(&mut self.writer, "(set-option :incremental true)").unwrap();
+        if self.unsat_cores {
+            writeln!(&mut self.writer, "(set-option :produce-unsat-cores true)").unwrap();
+        }
+        // The other solvers are incremental by default and reject the option.
+        if matches!(solver, SolverKind::Cvc5) {
+            writeln!(&mut self.writer, "(set-option :incremental true)").unwrap();
+        }
 
-        writeln!(&mut self.writer, "(set-logic {})", get_logic_string()).unwrap();
         writeln!(
             &mut self.writer,
-            "(define-sort F () (_ FiniteField {}))",
-            prime
+            "(set-logic {})",
+            get_logic_string(solver, encoding)
         )
         .unwrap();
+        let sort = match encoding {
+            FieldEncoding::FiniteField => format!("(_ FiniteField {})", prime),
+            FieldEncoding::Integer => String::from("Int"),
+            FieldEncoding::BitVector => format!("(_ BitVec {})", self.bit_width()),
+        };
+        writeln!(&mut self.writer, "(define-sort F () {})", sort).unwrap();
     }
     /// Writes the end of the SMT-LIB file.
     ///
@@ -85,7 +311,8 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     /// Writes a variable declaration in the SMT-LIB file.
     ///
     /// This function writes a variable declaration for a variable with the given name
-    /// in the SMT-LIB file. The variable is declared to be of sort `F` (finite field).
+    /// in the SMT-LIB file. The variable is declared to be of sort `F` (field element).
+    /// Unless `F` is a finite field, the variable is also constrained to be a canonical representative, i.e. less than the prime.
     /// If a variable with the same name has already been declared, this function does nothing.
     ///
     fn write_var(&mut self, name: String) {
@@ -94,6 +321,23 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         }
         self.vars.insert(name.clone(), true);
         writeln!(&mut self.writer, "(declare-fun {} () F)", name).unwrap();
+        match self.encoding {
+            FieldEncoding::FiniteField => {}
+            FieldEncoding::Integer => writeln!(
+                &mut self.writer,
+                "(assert (and (<= 0 {}) (< {} {})))",
+                name, name, self.prime
+            )
+            .unwrap(),
+            FieldEncoding::BitVector => writeln!(
+                &mut self.writer,
+                "(assert (bvult {} (_ bv{} {})))",
+                name,
+                self.prime,
+                self.bit_width()
+            )
+            .unwrap(),
+        }
     }
     /// Declares a function in the SMT-LIB file.
     ///
@@ -143,15 +387,11 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         } else {
             format!("({})", poly)
         };
+        let value = self.get_constant(&value);
         if matches!(op, analyzer::Operation::Equal) {
-            writeln!(&mut self.writer, "(assert ( = {} (as ff{} F)))", a, value).unwrap();
+            self.write_assertion(&format!("( = {} {})", a, value));
         } else if matches!(op, analyzer::Operation::NotEqual) {
-            writeln!(
-                &mut self.writer,
-                "(assert (not ( = {} (as ff{} F))))",
-                a, value
-            )
-            .unwrap();
+            self.write_assertion(&format!("(not ( = {} {}))", a, value));
         }
     }
     /// Writes a boolean assertion in the SMT-LIB file.
@@ -162,13 +402,17 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     ///
     fn write_assert_bool(&mut self, poly: String, op: analyzer::Operation) {
         if matches!(op, analyzer::Operation::Or) {
-            writeln!(&mut self.writer, "(assert (or {}))", poly).unwrap();
+            self.write_assertion(&format!("(or {})", poly));
         } else if matches!(op, analyzer::Operation::And) {
-            writeln!(&mut self.writer, "(assert (and {}))", poly).unwrap();
+            self.write_assertion(&format!("(and {})", poly));
         }
     }
+    /// Writes an assertion of an arbitrary boolean term in the SMT-LIB file.
+    fn write_assert_term(&mut self, term: String) {
+        self.write_assertion(&term);
+    }
     fn write_assert_boolean_func(&mut self, func_name: String, inputs: String) {
-        writeln!(&mut self.writer, "(assert ({} {}))", func_name, inputs).unwrap();
+        self.write_assertion(&format!("({} {})", func_name, inputs));
     }
     /// Returns a string representing an assertion in the SMT-LIB format.
     ///
@@ -192,10 +436,11 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
         } else {
             format!("({})", poly)
         };
+        let value = self.get_constant(&value);
         if matches!(op, analyzer::Operation::Equal) {
-            Ok(format!("( = {} (as ff{} F))", a, value))
+            Ok(format!("( = {} {})", a, value))
         } else if matches!(op, analyzer::Operation::NotEqual) {
-            Ok(format!("(not ( = {} (as ff{} F)))", a, value))
+            Ok(format!("(not ( = {} {}))", a, value))
         } else {
             Err(anyhow!("Invalid Operation: {:?}.", op))
         }
@@ -252,9 +497,15 @@ impl<'a, W: 'a + Write> Printer<'a, W> {
     }
 }
 
-pub fn write_start<W: Write>(w: &mut W, prime: String) -> Printer<W> {
+pub fn write_start<W: Write>(
+    w: &mut W,
+    prime: String,
+    encoding: FieldEncoding,
+    solver: SolverKind,
+    unsat_cores: bool,
+) -> Printer<W> {
     let mut p = Printer::new(w);
-    p.write_start(prime);
+    p.write_start(prime, encoding, solver, unsat_cores);
     p
 }
 
@@ -262,6 +513,10 @@ pub fn write_end<W: Write>(p: &mut Printer<W>) {
     p.write_end();
 }
 
+pub fn set_origin(p: &mut Printer<File>, origin: Option<ConstraintOrigin>) {
+    p.set_origin(origin);
+}
+
 pub fn write_var(p: &mut Printer<File>, name: String) {
     p.write_var(name);
 }
@@ -296,6 +551,10 @@ pub fn write_assert_bool(p: &mut Printer<File>, poly: String, op: analyzer::Oper
     p.write_assert_bool(poly, op);
 }
 
+pub fn write_assert_term(p: &mut Printer<File>, term: String) {
+    p.write_assert_term(term);
+}
+
 pub fn write_assert_boolean_func(
     p: &mut Printer<File>,
     func_name: String,
@@ -314,6 +573,10 @@ pub fn get_assert(
     p.get_assert(poly, value, nt, op)
 }
 
+pub fn write_bit_length(p: &mut Printer<File>, var: &str, bits: u32) {
+    p.write_bit_length(var, bits);
+}
+
 pub fn write_get_value(p: &mut Printer<File>, var: String) {
     p.write_get_value(var);
 }
@@ -333,3 +596,11 @@ pub fn get_or(p: &mut Printer<File>, or_str: String) -> String {
 pub fn get_and(p: &mut Printer<File>, and_str: String) -> String {
     p.get_and(and_str)
 }
+
+pub fn get_constant(p: &mut Printer<File>, value: String) -> String {
+    p.get_constant(&value)
+}
+
+pub fn get_neg(p: &mut Printer<File>, term: String) -> String {
+    p.get_neg(term)
+}
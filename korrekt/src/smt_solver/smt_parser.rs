@@ -5,8 +5,11 @@ use std::str;
 pub enum Satisfiability {
     Satisfiable,
     Unsatisfiable,
+    /// The solver gave up, e.g. because it hit its time or resource limit, for the given reason.
+    Unknown(String),
 }
 use anyhow::{anyhow, Context, Result};
+use num::BigUint;
 use regex::Regex;
 
 #[derive(Debug, PartialEq, Eq, Clone)]
@@ -32,13 +35,36 @@ pub struct ModelResult {
 /// struct containing the element value and its order.
 ///
 fn parse_field_element_from_string(value: &str) -> Result<FieldElement> {
-    // Remove #f and split by "m" to get (field element, field prime).
-    let mut elements = value[2..].split('m');
-    let value_str = elements.next().context("Failed to parse smt result!")?;
-    let order_str = elements.next().context("Failed to parse smt result!")?;
+    if let Some(ff_value) = value.strip_prefix("#f") {
+        // Remove #f and split by "m" to get (field element, field prime).
+        let mut elements = ff_value.split('m');
+        let value_str = elements.next().context("Failed to parse smt result!")?;
+        let order_str = elements.next().context("Failed to parse smt result!")?;
+        return Ok(FieldElement {
+            order: order_str.to_owned(),
+            element: value_str.to_owned(),
+        });
+    }
+    // The integer and bit-vector encodings do not carry the field prime in the model.
+    let element = if let Some(bits) = value.strip_prefix("#b") {
+        BigUint::parse_bytes(bits.as_bytes(), 2)
+    } else if let Some(hex) = value.strip_prefix("#x") {
+        BigUint::parse_bytes(hex.as_bytes(), 16)
+    } else if let Some(bv) = value.strip_prefix("(_ bv") {
+        bv.split_whitespace()
+            .next()
+            .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 10))
+    } else if let Some(ff) = value.strip_prefix("(as ff") {
+        ff.split_whitespace()
+            .next()
+            .and_then(|digits| BigUint::parse_bytes(digits.as_bytes(), 10))
+    } else {
+        BigUint::parse_bytes(value.as_bytes(), 10)
+    }
+    .context("Failed to parse smt result!")?;
     Ok(FieldElement {
-        order: order_str.to_owned(),
-        element: value_str.to_owned(),
+        order: String::new(),
+        element: element.to_string(),
     })
 }
 /// Extracts the model response from the SMT solver output.
@@ -51,7 +77,7 @@ pub fn extract_model_response(stream: String) -> Result<ModelResult> {
     let mut variables: HashMap<String, Variable> = HashMap::new();
     let first_line = lines.next().context("Failed to parse smt result!")?;
     if first_line.trim() == "sat" {
-        let re = Regex::new(r"\(\((\S+)\s+(\S+)\)\)").context("Failed to compile regex!")?;
+        let re = Regex::new(r"\(\((\S+)\s+(.+)\)\)").context("Failed to compile regex!")?;
         for line in lines {
             let trimmed_line = line.trim();
             if trimmed_line.is_empty() {
@@ -87,7 +113,41 @@ pub fn extract_model_response(stream: String) -> Result<ModelResult> {
             sat: Satisfiability::Unsatisfiable,
             result: variables,
         })
+    } else if first_line.trim() == "unknown" {
+        // The reason is only in the output if `(get-info :reason-unknown)` was queried.
+        let reason = stream
+            .lines()
+            .find_map(parse_reason_unknown)
+            .unwrap_or_else(|| "unknown".to_owned());
+        Ok(ModelResult {
+            sat: Satisfiability::Unknown(reason),
+            result: variables,
+        })
     } else {
         Err(anyhow!("SMT Solver Error: {}", first_line.trim()))
     }
 }
+
+/// Parses the response to `(get-info :reason-unknown)`, e.g. `(:reason-unknown timeout)`.
+pub fn parse_reason_unknown(response: &str) -> Option<String> {
+    let reason = response
+        .trim()
+        .strip_prefix("(:reason-unknown")?
+        .strip_suffix(')')?
+        .trim()
+        .trim_matches('"');
+    Some(reason.to_owned())
+}
+
+/// Parses the response to `(get-unsat-core)`, e.g. `(N-0 N-3)`, into the names of the assertions.
+pub fn parse_unsat_core(response: &str) -> Result<Vec<String>> {
+    let names = response
+        .trim()
+        .strip_prefix('(')
+        .and_then(|names| names.strip_suffix(')'))
+        .with_context(|| format!("Failed to parse unsat core: {}", response.trim()))?;
+    Ok(names
+        .split_whitespace()
+        .map(|name| name.trim_matches('|').to_owned())
+        .collect())
+}
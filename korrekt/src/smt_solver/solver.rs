@@ -0,0 +1,390 @@
+use anyhow::{anyhow, Context, Result};
+use std::collections::{HashMap, HashSet};
+use std::fs::{self, File};
+use std::io::{BufRead, BufReader, Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::process::{Child, ChildStdin, ChildStdout, Command, Stdio};
+
+use crate::io::analyzer_io_type::{AnalyzerConfig, FieldEncoding, SolverKind, SolverMode};
+
+use super::artifacts::SmtArtifacts;
+use super::smt_parser::{self, ModelResult, Satisfiability, Variable};
+
+/// An SMT-LIB solver that can be queried several times while assertions are added and retracted.
+pub trait Solver {
+    /// Sends SMT-LIB commands that have no response, such as declarations, definitions and assertions.
+    fn send(&mut self, commands: &str) -> Result<()>;
+
+    fn assert(&mut self, term: &str) -> Result<()> {
+        self.send(&format!("(assert {})\n", term))
+    }
+
+    fn push(&mut self) -> Result<()> {
+        self.send("(push)\n")
+    }
+
+    fn pop(&mut self) -> Result<()> {
+        self.send("(pop)\n")
+    }
+
+    fn check_sat(&mut self) -> Result<Satisfiability>;
+
+    /// Returns the value of each variable in the model of the last satisfiable `check_sat`.
+    fn get_value(&mut self, vars: &[String]) -> Result<HashMap<String, Variable>>;
+
+    /// Returns the names of the assertions of an unsat core of the last unsatisfiable `check_sat`.
+    fn get_unsat_core(&mut self) -> Result<Vec<String>>;
+
+    /// Checks satisfiability and, if satisfiable, returns the value of each variable in the model.
+    fn check_sat_and_get_values(&mut self, vars: &[String]) -> Result<ModelResult> {
+        let sat = self.check_sat()?;
+        let result = match sat {
+            Satisfiability::Satisfiable => self.get_value(vars)?,
+            Satisfiability::Unsatisfiable | Satisfiability::Unknown(_) => HashMap::new(),
+        };
+        Ok(ModelResult { sat, result })
+    }
+}
+
+/// Name of the executable of a solver.
+fn binary(kind: SolverKind) -> &'static str {
+    match kind {
+        SolverKind::Cvc5 => "cvc5",
+        SolverKind::Yices => "yices-smt2",
+        SolverKind::Z3 => "z3",
+        SolverKind::Bitwuzla => "bitwuzla",
+    }
+}
+
+/// Arguments to read SMT-LIB commands from stdin and answer them as they come.
+fn interactive_args(kind: SolverKind) -> &'static [&'static str] {
+    match kind {
+        SolverKind::Cvc5 => &[
+            "--lang=smt2",
+            "--incremental",
+            "--produce-models",
+            "--no-interactive",
+            "-q",
+        ],
+        SolverKind::Yices => &["--incremental"],
+        SolverKind::Z3 => &["-in", "-smt2"],
+        SolverKind::Bitwuzla => &["--lang", "smt2", "--produce-models"],
+    }
+}
+
+/// Arguments placed before the path of an SMT-LIB file to solve.
+fn file_args(kind: SolverKind) -> &'static [&'static str] {
+    match kind {
+        SolverKind::Cvc5 => &[],
+        SolverKind::Yices => &["--incremental"],
+        SolverKind::Z3 => &["-smt2"],
+        SolverKind::Bitwuzla => &["--produce-models"],
+    }
+}
+
+/// Arguments limiting the time and resources spent on each `(check-sat)`.
+///
+/// A solver that hits a limit answers `unknown` instead of running forever.
+fn limit_args(config: &AnalyzerConfig) -> Vec<String> {
+    let mut args = vec![];
+    if let Some(timeout) = config.timeout {
+        let millis = timeout.as_millis();
+        args.push(match config.solver {
+            SolverKind::Cvc5 => format!("--tlimit-per={}", millis),
+            // Yices only takes whole seconds.
+            SolverKind::Yices => format!("--timeout={}", (millis + 999) / 1000),
+            SolverKind::Z3 => format!("-t:{}", millis),
+            SolverKind::Bitwuzla => format!("--time-limit-per={}", millis),
+        });
+    }
+    if let Some(resource_limit) = config.resource_limit {
+        match config.solver {
+            SolverKind::Cvc5 => args.push(format!("--rlimit-per={}", resource_limit)),
+            SolverKind::Z3 => args.push(format!("rlimit={}", resource_limit)),
+            // Rejected by `check_config`.
+            SolverKind::Yices | SolverKind::Bitwuzla => {}
+        }
+    }
+    args
+}
+
+/// Checks that the solver supports the field encoding and the limits of `config`.
+pub fn check_config(config: &AnalyzerConfig) -> Result<()> {
+    let kind = config.solver;
+    let supported = match config.field_encoding {
+        FieldEncoding::FiniteField => matches!(kind, SolverKind::Cvc5 | SolverKind::Yices),
+        FieldEncoding::Integer => !matches!(kind, SolverKind::Bitwuzla),
+        FieldEncoding::BitVector => true,
+    };
+    if !supported {
+        return Err(anyhow!(
+            "{} does not support the {:?} field encoding!",
+            binary(kind),
+            config.field_encoding
+        ));
+    }
+    if config.resource_limit.is_some() && matches!(kind, SolverKind::Yices | SolverKind::Bitwuzla) {
+        return Err(anyhow!(
+            "{} does not support resource limits!",
+            binary(kind)
+        ));
+    }
+    Ok(())
+}
+
+/// A solver process that is kept open for the whole analysis and queried over stdin/stdout.
+///
+/// The solver keeps its state between queries, so `(push)`/`(pop)` scopes are solved incrementally
+/// instead of re-parsing the whole query every time.
+pub struct SolverProcess {
+    kind: SolverKind,
+    child: Child,
+    stdin: ChildStdin,
+    stdout: BufReader<ChildStdout>,
+}
+
+impl SolverProcess {
+    pub fn new(config: &AnalyzerConfig) -> Result<Self> {
+        let kind = config.solver;
+        let mut child = Command::new(binary(kind))
+            .args(interactive_args(kind))
+            .args(limit_args(config))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .spawn()
+            .with_context(|| {
+                format!(
+                    "Failed to start {}, is it installed and on PATH?",
+                    binary(kind)
+                )
+            })?;
+        let stdin = child.stdin.take().context("Failed to open solver stdin!")?;
+        let stdout = child
+            .stdout
+            .take()
+            .context("Failed to open solver stdout!")?;
+        Ok(SolverProcess {
+            kind,
+            child,
+            stdin,
+            stdout: BufReader::new(stdout),
+        })
+    }
+
+    fn read_line(&mut self) -> Result<String> {
+        let mut line = String::new();
+        let read = self
+            .stdout
+            .read_line(&mut line)
+            .context("Failed to read the solver response!")?;
+        if read == 0 {
+            return Err(anyhow!("{} exited unexpectedly!", binary(self.kind)));
+        }
+        if line.trim_start().starts_with("(error") {
+            return Err(anyhow!("SMT Solver Error: {}", line.trim()));
+        }
+        Ok(line)
+    }
+
+    /// Reads a response that may span several lines, until its parentheses are balanced.
+    fn read_s_expression(&mut self) -> Result<String> {
+        let mut response = self.read_line()?;
+        while response.matches('(').count() > response.matches(')').count() {
+            response.push_str(&self.read_line()?);
+        }
+        Ok(response)
+    }
+}
+
+impl Solver for SolverProcess {
+    fn send(&mut self, commands: &str) -> Result<()> {
+        self.stdin
+            .write_all(commands.as_bytes())
+            .and_then(|_| self.stdin.flush())
+            .context("Failed to send commands to the solver!")
+    }
+
+    fn check_sat(&mut self) -> Result<Satisfiability> {
+        self.send("(check-sat)\n")?;
+        let line = self.read_line()?;
+        match line.trim() {
+            "sat" => Ok(Satisfiability::Satisfiable),
+            "unsat" => Ok(Satisfiability::Unsatisfiable),
+            "unknown" => {
+                self.send("(get-info :reason-unknown)\n")?;
+                let line = self.read_line()?;
+                let reason =
+                    smt_parser::parse_reason_unknown(&line).unwrap_or_else(|| "unknown".to_owned());
+                Ok(Satisfiability::Unknown(reason))
+            }
+            response => Err(anyhow!("SMT Solver Error: {}", response)),
+        }
+    }
+
+    fn get_value(&mut self, vars: &[String]) -> Result<HashMap<String, Variable>> {
+        let mut response = String::from("sat\n");
+        for var in vars {
+            self.send(&format!("(get-value ({}))\n", var))?;
+            response.push_str(&self.read_line()?);
+        }
+        Ok(smt_parser::extract_model_response(response)
+            .context("Failed to parse smt result!")?
+            .result)
+    }
+
+    fn get_unsat_core(&mut self) -> Result<Vec<String>> {
+        self.send("(get-unsat-core)\n")?;
+        let response = self.read_s_expression()?;
+        smt_parser::parse_unsat_core(&response)
+    }
+}
+
+impl Drop for SolverProcess {
+    fn drop(&mut self) {
+        let _ = self.send("(exit)\n");
+        let _ = self.child.wait();
+    }
+}
+
+/// Runs a fresh solver process on a file for every query.
+///
+/// This is the fallback when an interactive session is not available: every `check_sat` writes the
+/// commands sent so far to `script_path` and solves them from scratch.
+/// The reason of an `unknown` answer is not queried, so it is always reported as `unknown`.
+pub struct SolverFile {
+    kind: SolverKind,
+    limit_args: Vec<String>,
+    script: String,
+    script_path: PathBuf,
+}
+
+impl SolverFile {
+    pub fn new(config: &AnalyzerConfig, script_path: PathBuf) -> Self {
+        SolverFile {
+            kind: config.solver,
+            limit_args: limit_args(config),
+            script: String::new(),
+            script_path,
+        }
+    }
+
+    fn run(&self, vars: &[String]) -> Result<ModelResult> {
+        let mut queries = String::from("(check-sat)\n");
+        for var in vars {
+            queries.push_str(&format!("(get-value ({}))\n", var));
+        }
+        smt_parser::extract_model_response(self.run_script(&queries)?)
+            .context("Failed to parse smt result!")
+    }
+
+    /// Runs the solver on the commands sent so far followed by `queries`, and returns its output.
+    fn run_script(&self, queries: &str) -> Result<String> {
+        let mut script = self.script.clone();
+        script.push_str(queries);
+        fs::write(&self.script_path, script).context("Failed to write the solver script!")?;
+        let output = Command::new(binary(self.kind))
+            .args(file_args(self.kind))
+            .args(&self.limit_args)
+            .arg(&self.script_path)
+            .output()
+            .with_context(|| {
+                format!(
+                    "Failed to run {}, is it installed and on PATH?",
+                    binary(self.kind)
+                )
+            })?;
+        Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+}
+
+impl Solver for SolverFile {
+    fn send(&mut self, commands: &str) -> Result<()> {
+        self.script.push_str(commands);
+        Ok(())
+    }
+
+    fn check_sat(&mut self) -> Result<Satisfiability> {
+        Ok(self.run(&[])?.sat)
+    }
+
+    fn get_value(&mut self, vars: &[String]) -> Result<HashMap<String, Variable>> {
+        Ok(self.run(vars)?.result)
+    }
+
+    fn get_unsat_core(&mut self) -> Result<Vec<String>> {
+        let output = self.run_script("(check-sat)\n(get-unsat-core)\n")?;
+        let (sat, core) = output
+            .split_once('\n')
+            .context("Failed to parse the unsat core!")?;
+        if sat.trim() != "unsat" {
+            return Err(anyhow!("SMT Solver Error: {}", sat.trim()));
+        }
+        smt_parser::parse_unsat_core(core)
+    }
+
+    fn check_sat_and_get_values(&mut self, vars: &[String]) -> Result<ModelResult> {
+        self.run(vars)
+    }
+}
+
+/// A solver fed with the SMT-LIB file written by the analyzer.
+///
+/// The analyzer writes every declaration and assertion to the SMT file through a `Printer`.
+/// Before each query, the commands appended to the file since the previous query are forwarded to the solver.
+pub struct SmtSession {
+    solver: Box<dyn Solver>,
+    smt_file_path: PathBuf,
+    offset: u64,
+}
+
+/// Starts the solver selected by `config`, in the mode selected by `config`.
+pub fn start_solver(config: &AnalyzerConfig, artifacts: &SmtArtifacts) -> Result<Box<dyn Solver>> {
+    Ok(match config.solver_mode {
+        SolverMode::Incremental => Box::new(SolverProcess::new(config)?),
+        SolverMode::File => Box::new(SolverFile::new(config, artifacts.script_path().to_owned())),
+    })
+}
+
+impl SmtSession {
+    pub fn new(config: &AnalyzerConfig, artifacts: &SmtArtifacts) -> Result<Self> {
+        Ok(SmtSession {
+            solver: start_solver(config, artifacts)?,
+            smt_file_path: artifacts.smt_file_path().to_owned(),
+            offset: 0,
+        })
+    }
+
+    fn forward_new_commands(&mut self) -> Result<()> {
+        let mut smt_file = File::open(&self.smt_file_path).context("Failed to open SMT file!")?;
+        smt_file
+            .seek(SeekFrom::Start(self.offset))
+            .context("Failed to read SMT file!")?;
+        let mut commands = String::new();
+        let read = smt_file
+            .read_to_string(&mut commands)
+            .context("Failed to read SMT file!")?;
+        self.offset += read as u64;
+        if !commands.is_empty() {
+            self.solver.send(&commands)?;
+        }
+        Ok(())
+    }
+
+    /// Returns the SMT-LIB commands of the SMT file written so far.
+    pub fn commands(&self) -> Result<String> {
+        fs::read_to_string(&self.smt_file_path).context("Failed to read SMT file!")
+    }
+
+    /// Returns the names of the assertions of an unsat core of the last query, which must be unsatisfiable.
+    pub fn unsat_core(&mut self) -> Result<Vec<String>> {
+        self.solver.get_unsat_core()
+    }
+
+    /// Solves the SMT file as written so far and returns the value of `variables` in the model.
+    pub fn solve(&mut self, variables: &HashSet<String>) -> Result<ModelResult> {
+        self.forward_new_commands()?;
+        let mut vars: Vec<String> = variables.iter().cloned().collect();
+        vars.sort();
+        self.solver.check_sat_and_get_values(&vars)
+    }
+}
@@ -8,8 +8,6 @@ mod tests {
         analyzer_io_type::{AnalyzerOutputStatus, VerificationInput, VerificationMethod},
     };
     use crate::sample_circuits::axiom as sample_circuits;
-    use halo2curves::bn256;
-    use num::{BigInt, Num};
     use axiom_halo2_proofs::halo2curves::bn256::Fr;
     use std::collections::HashMap;
     use std::marker::PhantomData;
@@ -78,12 +76,6 @@ mod tests {
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -93,7 +85,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
@@ -109,12 +101,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -125,7 +111,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -141,12 +127,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -157,7 +137,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -178,12 +158,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -194,7 +168,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -215,12 +189,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -231,7 +199,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -247,12 +215,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -263,7 +225,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -280,12 +242,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -296,7 +252,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -318,12 +274,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -334,7 +284,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -356,12 +306,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -372,7 +316,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -432,12 +376,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -448,7 +386,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         println!("output_status: {:?}", output_status);
@@ -463,12 +401,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -478,7 +410,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -493,12 +425,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -508,7 +434,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -523,12 +449,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -538,7 +458,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -552,12 +472,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -567,7 +481,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -581,12 +495,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -596,7 +504,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -610,12 +518,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -625,7 +527,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -644,12 +546,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -659,7 +555,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -678,12 +574,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -693,7 +583,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -712,12 +602,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -727,7 +611,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -1,16 +1,27 @@
 #[cfg(test)]
 #[cfg(feature = "use_pse_halo2_proofs")]
 mod tests {
-    use crate::circuit_analyzer::analyzer::Analyzer;
+    use crate::circuit_analyzer::analyzable::{field_modulus, field_to_biguint};
+    use crate::circuit_analyzer::analyzer::{Analyzer, Specification};
+    use crate::circuit_analyzer::replay;
     use crate::io::analyzer_io_type::LookupMethod;
     use crate::io::{
+        analyzer_io::output_result,
         analyzer_io_type,
-        analyzer_io_type::{AnalyzerOutputStatus, VerificationInput, VerificationMethod},
+        analyzer_io_type::{
+            AnalyzerOutputStatus, Assumption, CellLocation, CellScope, CellSelector, ColumnLocation, ColumnType,
+            ConstraintOrigin, Determinism, FieldEncoding, Finding, OutputFormat, SolverKind, SolverMode, VerificationInput,
+            VerificationMethod,
+        },
+        report::{self, REPORT_SCHEMA_VERSION},
     };
     use crate::sample_circuits::pse as sample_circuits;
+    use num::{BigUint, Num};
+    use group::ff::Field;
     use halo2curves::bn256;
-    use num::{BigInt, Num};
     use pse_halo2_proofs::halo2curves::bn256::Fr;
+    use pse_halo2_proofs::plonk::Expression;
+    use pse_halo2_proofs::halo2curves::{grumpkin, pasta, secp256k1};
     use std::collections::HashMap;
     use std::marker::PhantomData;
 
@@ -78,12 +89,6 @@ mod tests {
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -93,7 +98,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
@@ -109,12 +114,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -125,7 +124,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -141,12 +140,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -157,7 +150,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -178,12 +171,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -194,7 +181,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -215,12 +202,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -231,7 +212,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -247,12 +228,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -263,7 +238,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -280,12 +255,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -296,7 +265,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -318,12 +287,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -334,7 +297,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -356,12 +319,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -372,7 +329,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -431,12 +388,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -447,7 +398,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         println!("output_status: {:?}", output_status);
@@ -461,12 +412,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -477,7 +422,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         println!("output_status: {:?}", output_status);
@@ -492,12 +437,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -507,7 +446,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -522,12 +461,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -537,7 +470,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -552,12 +485,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -567,7 +494,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -581,12 +508,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -596,7 +517,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -610,12 +531,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -625,7 +540,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -639,12 +554,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -654,7 +563,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -673,12 +582,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -688,7 +591,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -707,12 +610,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -722,7 +619,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -741,12 +638,189 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+    #[test]
+    fn large_constants_fixed_converted_test() {
+        let circuit =
+            sample_circuits::field_constants::large_constants::LargeConstantsCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let modulus = BigUint::from_str_radix(
+            bn256::fr::MODULUS_STR.trim_start_matches("0x"),
+            16,
+        )
+        .unwrap();
+        // The fixed cell holds -2, i.e. p - 2, which does not fit in 64 bits.
+        assert!(analyzer.fixed_converted[0][0].eq(&(modulus.clone() - 2u32)));
+        assert!(field_to_biguint(&-Fr::ONE).eq(&(modulus - 1u32)));
+    }
+
+    #[test]
+    fn large_constants_not_under_constrained_test() {
+        let circuit =
+            sample_circuits::field_constants::large_constants::LargeConstantsCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn multirow_not_under_constrained_random_input_test() {
+        let circuit = sample_circuits::bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuit::<
+            Fr,
+        >::default();
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn multirow_under_constrained_random_input_test() {
+        // The selector is only enabled on row 0, so the `b0_binary_check` gate must not be
+        // applied to `b1` on row 1.
+        let circuit = sample_circuits::bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuitUnderConstrained::<
+            Fr,
+        >::default();
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn instance_gate_instance_cells_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The instance cell is only queried by a gate, it is not part of any copy constraint.
+        assert!(analyzer.permutation.is_empty());
+        assert!(analyzer.instace_cells.len().eq(&1));
+        assert!(analyzer.instace_cells.contains_key("I-0-0"));
+    }
+
+    #[test]
+    fn instance_gate_two_rows_instance_cells_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitTwoRows::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The gates are disabled on the second row of the region, so I-0-1 is not a public input of the circuit.
+        assert!(analyzer.instace_cells.len().eq(&1));
+        assert!(analyzer.instace_cells.contains_key("I-0-0"));
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 6);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
-            .to_string();
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_specific_input_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -756,9 +830,1381 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        // Both 3 and -3 are square roots of 9.
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn rlc_synthesizes_every_phase_once_test() {
+        let circuit = sample_circuits::challenges::rlc::RlcCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The layout is only recorded while synthesizing the first phase.
+        assert!(analyzer.regions.len().eq(&1));
+        assert!(analyzer.permutation.len().eq(&1));
+        assert!(analyzer.instace_cells.len().eq(&1));
+    }
+
+    #[test]
+    fn rlc_not_under_constrained_random_input_test() {
+        let circuit = sample_circuits::challenges::rlc::RlcCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn rlc_under_constrained_random_input_test() {
+        let circuit =
+            sample_circuits::challenges::rlc::RlcCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn rlc_seeded_challenges_test() {
+        let circuit = sample_circuits::challenges::rlc::RlcCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut challenges = vec![];
+        for _ in 0..2 {
+            let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+            analyzer.config.seed = Some(42);
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Random,
+                verification_input: VerificationInput {
+                    instances_string: analyzer.instace_cells.clone(),
+                    iterations: 1,
+                },
+                lookup_method: LookupMethod::InlineConstraints,
+            };
+            let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+            assert!(analyzer_output
+                .output_status
+                .eq(&AnalyzerOutputStatus::NotUnderconstrained));
+            challenges.push(analyzer_output.challenges.unwrap());
+        }
+
+        // The same seed draws the same challenges, and the seed is reported to replay the run.
+        assert!(challenges[0].seed.eq(&42));
+        assert!(challenges[0].values.contains_key("C-0"));
+        assert!(challenges[0].eq(&challenges[1]));
+    }
+
+    #[test]
+    fn shuffle_not_under_constrained_random_input_test() {
+        let circuit = sample_circuits::shuffle_circuits::shuffle::ShuffleCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn shuffle_under_constrained_random_input_test() {
+        let circuit = sample_circuits::shuffle_circuits::shuffle::ShuffleCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn shuffle_not_under_constrained_global_test() {
+        let circuit = sample_circuits::shuffle_circuits::shuffle::ShuffleCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn shuffle_any_order_under_constrained_global_test() {
+        let circuit = sample_circuits::shuffle_circuits::shuffle::ShuffleCircuitAnyOrder::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        // The two copies have to be able to choose different permutations of the public inputs.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn dynamic_lookup_not_under_constrained_inline_test() {
+        let circuit = sample_circuits::lookup_circuits::dynamic_lookup::DynamicLookupCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The table values are the public inputs I-0-0 to I-0-3, the key is I-0-4.
+        assert!(analyzer.instace_cells.len().eq(&5));
+        let mut specified_instance_cols = HashMap::new();
+        for row in 0..4 {
+            specified_instance_cols.insert(format!("I-0-{}", row), 10 * (row + 1));
+        }
+        specified_instance_cols.insert("I-0-4".to_owned(), 3);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn dynamic_lookup_not_under_constrained_uninterpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::dynamic_lookup::DynamicLookupCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The table values are the public inputs I-0-0 to I-0-3, the key is I-0-4.
+        assert!(analyzer.instace_cells.len().eq(&5));
+        let mut specified_instance_cols = HashMap::new();
+        for row in 0..4 {
+            specified_instance_cols.insert(format!("I-0-{}", row), 10 * (row + 1));
+        }
+        specified_instance_cols.insert("I-0-4".to_owned(), 3);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Uninterpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn dynamic_lookup_under_constrained_inline_test() {
+        let circuit = sample_circuits::lookup_circuits::dynamic_lookup::DynamicLookupCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The table values are the public inputs I-0-0 to I-0-3, the key is I-0-4.
+        assert!(analyzer.instace_cells.len().eq(&5));
+        let mut specified_instance_cols = HashMap::new();
+        for row in 0..4 {
+            specified_instance_cols.insert(format!("I-0-{}", row), 10 * (row + 1));
+        }
+        specified_instance_cols.insert("I-0-4".to_owned(), 3);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn dynamic_lookup_under_constrained_uninterpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::dynamic_lookup::DynamicLookupCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The table values are the public inputs I-0-0 to I-0-3, the key is I-0-4.
+        assert!(analyzer.instace_cells.len().eq(&5));
+        let mut specified_instance_cols = HashMap::new();
+        for row in 0..4 {
+            specified_instance_cols.insert(format!("I-0-{}", row), 10 * (row + 1));
+        }
+        specified_instance_cols.insert("I-0-4".to_owned(), 3);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Uninterpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn dynamic_lookup_ungated_table_not_under_constrained_inline_test() {
+        let circuit = sample_circuits::lookup_circuits::dynamic_lookup::DynamicLookupCircuitUngatedTable::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // Only the first four rows of the table are assigned, the unassigned rows of `tv` are no free entries.
+        assert!(analyzer.instace_cells.len().eq(&5));
+        let mut specified_instance_cols = HashMap::new();
+        for row in 0..4 {
+            specified_instance_cols.insert(format!("I-0-{}", row), 10 * (row + 1));
+        }
+        specified_instance_cols.insert("I-0-4".to_owned(), 3);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+        assert!(analyzer.table_variables.is_empty());
+    }
+
+    #[test]
+    fn range_decomp_not_under_constrained_interpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuit::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Interpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn range_decomp_not_under_constrained_inline_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuit::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn range_decomp_under_constrained_interpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Interpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        // Without a range check on hi, hi = (42 - lo) / 8 is a valid witness for every lo in 0..8.
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn analyze_unused_custom_gates_findings_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let findings = analyzer.analyze_unused_custom_gates().unwrap().findings;
+        // Only the selector of the "mul" gate is enabled.
+        assert_eq!(
+            findings,
+            vec![Finding::UnusedGate {
+                gate_index: 1,
+                gate_name: "add".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_findings_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let findings = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .findings;
+        assert_eq!(findings.len(), 1);
+        match &findings[0] {
+            Finding::Underconstrained {
+                model,
+                equivalent_model,
+                differing_cells,
+            } => {
+                assert_eq!(model["I-0-0"], equivalent_model["I-0-0"]);
+                assert_ne!(model["A-0-0"], equivalent_model["A-0-0"]);
+                assert_eq!(
+                    differing_cells,
+                    &vec![CellLocation {
+                        cell: "A-0-0".to_owned(),
+                        region_name: Some("The Region".to_owned()),
+                        column: ColumnLocation {
+                            column_type: ColumnType::Advice,
+                            index: 0,
+                        },
+                        annotation: Some("a".to_owned()),
+                        row: 0,
+                    }]
+                );
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    #[test]
+    fn instance_gate_global_verification_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::Underconstrained {
+                model,
+                equivalent_model,
+                differing_cells,
+            } => {
+                assert_eq!(model["I-0-0"], equivalent_model["I-0-0"]);
+                assert_ne!(model["A-0-0"], equivalent_model["A-0-0"]);
+                assert_eq!(differing_cells.len(), 1);
+                assert_eq!(differing_cells[0].cell, "A-0-0");
+                assert_eq!(differing_cells[0].annotation, Some("a".to_owned()));
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    fn is_zero_input(verification_method: VerificationMethod) -> analyzer_io_type::AnalyzerInput {
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 0);
+        analyzer_io_type::AnalyzerInput {
+            verification_method,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        }
+    }
+
+    #[test]
+    fn is_zero_output_cells_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        // The inverse is free for a zero input.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        match &analyzer_output.findings[0] {
+            Finding::Underconstrained {
+                differing_cells, ..
+            } => {
+                assert_eq!(differing_cells.len(), 1);
+                assert_eq!(differing_cells[0].cell, "A-0-0");
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        // The output is determined.
+        for verification_method in [VerificationMethod::Specific, VerificationMethod::Global] {
+            let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+            analyzer.output_cells = vec![CellSelector::Annotation("out".to_owned())];
+            let output_status = analyzer
+                .analyze_underconstrained(is_zero_input(verification_method))
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.output_cells = vec![CellSelector::Region("No Such Region".to_owned())];
+        assert!(analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .is_err());
+    }
+
+    #[test]
+    fn is_zero_hint_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        for verification_method in [VerificationMethod::Specific, VerificationMethod::Global] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .hint_annotation("inv")
+                .build()
+                .unwrap();
+            let output_status = analyzer
+                .analyze_underconstrained(is_zero_input(verification_method))
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+    }
+
+    #[test]
+    fn is_zero_assumption_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+        let non_zero = Assumption::NotEqual {
+            cell: "I-0-0".to_owned(),
+            value: 0,
+        };
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let output_status = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Global))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+        // The inverse is only free for a zero input.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(non_zero.clone())
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Global))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        assert_eq!(analyzer_output.assumptions, vec![non_zero.clone()]);
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Text, &mut report).unwrap();
+        assert!(String::from_utf8(report)
+            .unwrap()
+            .contains("Assuming I-0-0 != 0."));
+
+        // The public input 0 contradicts the assumption.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(non_zero.clone())
+            .build()
+            .unwrap();
+        analyzer.config.unsat_cores = true;
+        let findings = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .unwrap()
+            .findings;
+        match &findings[0] {
+            Finding::Overconstrained {
+                conflicting_constraints,
+            } => assert!(
+                conflicting_constraints.contains(&ConstraintOrigin::Assumption {
+                    assumption: non_zero,
+                })
+            ),
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(Assumption::Equal {
+                cell: "I-1-0".to_owned(),
+                value: 0,
+            })
+            .build()
+            .unwrap();
+        assert!(analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_bit_length_assumption_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        // Only one of the square roots a and -a is a small integer.
+        for field_encoding in [
+            FieldEncoding::FiniteField,
+            FieldEncoding::Integer,
+            FieldEncoding::BitVector,
+        ] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .assume("A-0-0 < 2^8".parse().unwrap())
+                .build()
+                .unwrap();
+            analyzer.config.field_encoding = field_encoding;
+            let analyzer_input = global_input(&analyzer.instace_cells);
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input)
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+    }
+
+    fn global_input(instance_cells: &HashMap<String, i64>) -> analyzer_io_type::AnalyzerInput {
+        analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Global,
+            verification_input: VerificationInput {
+                instances_string: instance_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        }
+    }
+
+    #[test]
+    fn instance_gate_specification_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        // i = 2a and b = a*a.
+        for field_encoding in [
+            FieldEncoding::FiniteField,
+            FieldEncoding::Integer,
+            FieldEncoding::BitVector,
+        ] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .spec("I-0-0 = 2 * A-0-0".parse().unwrap())
+                .spec("4 * A-1-0 = I-0-0 * I-0-0".parse().unwrap())
+                .build()
+                .unwrap();
+            analyzer.config.field_encoding = field_encoding;
+            let analyzer_output = analyzer
+                .analyze_specification(global_input(&analyzer.instace_cells))
+                .unwrap();
+            assert!(analyzer_output
+                .output_status
+                .eq(&AnalyzerOutputStatus::SpecificationHolds));
+            assert!(analyzer_output.findings.is_empty());
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-1-0 = I-0-0".parse().unwrap())
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::SpecificationViolated));
+        match &analyzer_output.findings[..] {
+            [Finding::SpecificationViolation {
+                specification,
+                model,
+            }] => {
+                assert_eq!(specification, "A-1-0 = I-0-0");
+                assert!(model["A-1-0"].ne(&model["I-0-0"]));
+            }
+            findings => panic!("unexpected findings: {:?}", findings),
+        }
+
+        // The polynomial of the "square" gate is zero at the row of the region, and nowhere required to be one.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let square = analyzer.cs.gates[1].polys[0].clone();
+        analyzer.specifications = vec![Specification::Expressions {
+            lhs: square.clone(),
+            rhs: Expression::Constant(Fr::ZERO),
+            row: 0,
+        }];
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationHolds));
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec_expressions(square, Expression::Constant(Fr::ONE), 0)
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+
+        // A-2-0 is not a cell of the circuit, and there must be a specification to check.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-0 = 0".parse().unwrap())
+            .build()
+            .unwrap();
+        assert!(analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .is_err());
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        assert!(analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .is_err());
+    }
+
+    #[test]
+    fn lookup_xor_specification_test() {
+        let circuit = sample_circuits::lookup_circuits::lookup::MyCircuit::<Fr>(PhantomData);
+        let k = 11;
+
+        // The cells of row 1 are looked up in the XOR table of the 3-bit integers less than 6.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = xor(A-0-1, A-1-1, 3)".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationHolds));
+
+        // The operands 4 and 5 do not fit in 2 bits.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = xor(A-0-1, A-1-1, 2)".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = A-0-1 + A-1-1".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+    }
+
+    #[test]
+    fn unconstrained_cells_hint_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        // Only the cells of "test 2" are unconstrained, no gate is enabled in this region.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let unconstrained = analyzer
+            .analyze_unconstrained_cells()
+            .unwrap()
+            .findings
+            .len();
+        assert!(unconstrained > 1);
+
+        // Column 1 has a single cell in "test 2".
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .hint(CellSelector::Column(1))
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer.analyze_unconstrained_cells().unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::UnconstrainedCells));
+        assert_eq!(analyzer_output.findings.len(), unconstrained - 1);
+        for finding in &analyzer_output.findings {
+            match finding {
+                Finding::UnconstrainedCell { region_name, .. } => assert_eq!(region_name, "test 2"),
+                finding => panic!("unexpected finding: {:?}", finding),
+            }
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .hint_region("test 2")
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_unconstrained_cells()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NoUnconstrainedCells));
+    }
+
+    #[test]
+    fn unconstrained_output_cells_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        // The cells of "test 2" occur in no constraint, so they can take any value.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .output_cell(CellSelector::Region("test 2".to_owned()))
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(global_input(&analyzer.instace_cells))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        assert_eq!(analyzer_output.findings.len(), 3);
+        for finding in &analyzer_output.findings {
+            match finding {
+                Finding::FreeCell { cell } => {
+                    assert_eq!(cell.region_name.as_deref(), Some("test 2"))
+                }
+                finding => panic!("unexpected finding: {:?}", finding),
+            }
+        }
+    }
+    #[test]
+    fn instance_gate_not_under_constrained_global_verification_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn unused_gates_json_report_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer.analyze_unused_custom_gates().unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Json, &mut report).unwrap();
+
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        assert_eq!(report["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(report["status"], "UnusedCustomGates");
+        assert_eq!(report["findings"][0]["kind"], "unused_gate");
+        assert_eq!(report["findings"][0]["gate_name"], "add");
+    }
+
+    #[test]
+    fn unused_gates_sarif_report_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer.analyze_unused_custom_gates().unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Sarif, &mut report).unwrap();
+
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        assert_eq!(report["version"], "2.1.0");
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "unused-gate");
+        assert_eq!(
+            results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "gate[1]:add"
+        );
+    }
+
+    #[test]
+    fn under_constrained_sarif_fingerprint_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuitUnderConstrained::<
+                Fr,
+            >::default();
+        let k: u32 = 11;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Sarif, &mut report).unwrap();
+
+        // The fingerprint names the columns of the differing cells, not their rows, which depend on the witnesses.
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert!(!results.is_empty());
+        let fingerprint = results[0]["partialFingerprints"]["korrektLocation/v1"]
+            .as_str()
+            .unwrap();
+        assert!(fingerprint.starts_with("circuit/region:"));
+        assert!(!fingerprint.contains("A-"));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_file_solver_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver_mode = SolverMode::File;
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_integer_encoding_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.field_encoding = FieldEncoding::Integer;
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_bit_vector_encoding_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.field_encoding = FieldEncoding::BitVector;
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn unsupported_field_encoding_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver = SolverKind::Z3;
+        analyzer.config.field_encoding = FieldEncoding::FiniteField;
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        assert!(analyzer
+            .analyze_underconstrained(analyzer_input)
+            .is_err());
+    }
+
+    #[test]
+    fn smt_artifacts_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let output_dir = std::env::temp_dir().join("korrekt-smt-artifacts-test-pse");
+        let _ = std::fs::remove_dir_all(&output_dir);
+        for keep_artifacts in [false, true] {
+            let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+            analyzer.config.output_dir = Some(output_dir.clone());
+            analyzer.config.keep_artifacts = keep_artifacts;
+
+            let mut specified_instance_cols = HashMap::new();
+            specified_instance_cols.insert("I-0-0".to_owned(), 9);
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Specific,
+                verification_input: VerificationInput {
+                    instances_string: specified_instance_cols,
+                    iterations: 1,
+                },
+                lookup_method: LookupMethod::InlineConstraints,
+            };
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input)
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+            let smt_files = std::fs::read_dir(&output_dir).unwrap().count();
+            assert_eq!(smt_files, usize::from(keep_artifacts));
+        }
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn analyze_underconstrained_resource_limit_inconclusive_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let k: u32 = 11;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.resource_limit = Some(1);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(matches!(
+            output_status,
+            AnalyzerOutputStatus::Inconclusive { .. }
+        ));
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver = SolverKind::Yices;
+        analyzer.config.resource_limit = Some(1);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        assert!(analyzer
+            .analyze_underconstrained(analyzer_input)
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_overconstrained_unsat_core_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.unsat_cores = true;
+
+        // 7 generates the multiplicative group of the field, so it is not a square.
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 7);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Overconstrained));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::Overconstrained {
+                conflicting_constraints,
+            } => {
+                assert!(conflicting_constraints.contains(&ConstraintOrigin::Gate {
+                    gate_index: 0,
+                    gate_name: "square".to_owned(),
+                    polynomial_index: 0,
+                    region_name: "The Region".to_owned(),
+                    row: 0,
+                }));
+                assert!(conflicting_constraints.contains(&ConstraintOrigin::PublicInput {
+                    cell: "I-0-0".to_owned(),
+                    value: 7,
+                }));
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    #[test]
+    fn instance_gate_cell_determinism_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 6);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let cells = analyzer
+            .cell_determinism(&analyzer_input, &CellScope::All)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            cells.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("A-0-0".to_owned(), Determinism::Determined),
+                ("A-1-0".to_owned(), Determinism::Determined),
+            ]
+        );
+
+        let output_status = analyzer
+            .analyze_cell_determinism(analyzer_input, CellScope::All)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NoFreeCells));
+    }
+
+    #[test]
+    fn cell_determinism_uninterpreted_lookups_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The models of the parallel queries would not be checked against the lookup tables.
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Uninterpreted,
+        };
+        assert!(analyzer
+            .analyze_cell_determinism(analyzer_input, CellScope::All)
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_cell_determinism_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = |instances_string| analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string,
+                iterations: 2,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer
+            .analyze_cell_determinism(
+                analyzer_input(analyzer.instace_cells.clone()),
+                CellScope::Region("The Region".to_owned()),
+            )
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::FreeCells));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::FreeCell { cell } => {
+                assert_eq!(cell.cell, "A-0-0");
+                assert_eq!(cell.region_name.as_deref(), Some("The Region"));
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        // Nothing is assigned to the second advice column.
+        let cells = analyzer
+            .cell_determinism(
+                &analyzer_input(analyzer.instace_cells.clone()),
+                &CellScope::Column(1),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(cells.is_empty());
+    }
+
+    #[test]
+    fn counterexample_replay_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+
+        let counterexamples = analyzer.counterexamples(&analyzer_output);
+        assert!(counterexamples.len().eq(&1));
+        let counterexample = &counterexamples[0];
+        assert!(counterexample.witness.ne(&counterexample.equivalent_witness));
+        assert!(replay::replay_witness(&circuit, k, &counterexample.witness).is_ok());
+        assert!(replay::replay_witness(&circuit, k, &counterexample.equivalent_witness).is_ok());
+
+        // A witness for another public input does not satisfy the circuit.
+        let mut tampered = counterexample.witness.clone();
+        tampered.insert("I-0-0".to_owned(), "10".to_owned());
+        assert!(replay::replay_witness(&circuit, k, &tampered).is_err());
+
+        let json = report::counterexamples_to_json(&counterexamples).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value["schema_version"].eq(&REPORT_SCHEMA_VERSION));
+        assert!(value["counterexamples"][0]["witness"]["I-0-0"].eq("9"));
+    }
+
+    #[test]
+    fn field_modulus_test() {
+        let modulus =
+            |hex: &str| BigUint::from_str_radix(hex.trim_start_matches("0x"), 16).unwrap();
+        assert!(field_modulus::<Fr>().eq(&modulus(bn256::fr::MODULUS_STR)));
+        // The scalar field of grumpkin is the base field of bn256.
+        assert!(field_modulus::<grumpkin::Fr>().eq(&modulus(
+            "30644e72e131a029b85045b68181585d97816a916871ca8d3c208c16d87cfd47"
+        )));
+        assert!(field_modulus::<grumpkin::Fq>().eq(&modulus(bn256::fr::MODULUS_STR)));
+        assert!(field_modulus::<secp256k1::Fp>().eq(&modulus(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f"
+        )));
+        assert!(field_modulus::<secp256k1::Fq>().eq(&modulus(
+            "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141"
+        )));
+        assert!(field_modulus::<pasta::Fp>().eq(&modulus(
+            "40000000000000000000000000000000224698fc094cf91b992d30ed00000001"
+        )));
+        assert!(field_modulus::<pasta::Fq>().eq(&modulus(
+            "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001"
+        )));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_grumpkin_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<
+            grumpkin::Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+}
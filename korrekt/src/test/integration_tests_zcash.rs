@@ -1,17 +1,29 @@
 #[cfg(test)]
 #[cfg(feature = "use_zcash_halo2_proofs")]
 mod tests {
-    use crate::circuit_analyzer::analyzer::Analyzer;
+    use crate::circuit_analyzer::analyzable::{field_modulus, field_to_biguint};
+    use crate::circuit_analyzer::analyzer::{Analyzer, Specification};
+    use crate::circuit_analyzer::replay;
+    use crate::circuit_analyzer::registry::CircuitRegistry;
+    use crate::cli::{Cli, Command};
     use crate::io::analyzer_io_type::LookupMethod;
     use crate::io::{
+        analyzer_io::output_result,
         analyzer_io_type,
-        analyzer_io_type::{AnalyzerOutputStatus, VerificationInput, VerificationMethod},
+        analyzer_io_type::{
+            AnalyzerOutputStatus, Assumption, CellLocation, CellScope, CellSelector, ColumnLocation, ColumnType,
+            ConstraintOrigin, Determinism, FieldEncoding, Finding, OutputFormat, SolverKind, SolverMode, SpecEquation,
+            SpecTerm, VerificationInput, VerificationMethod,
+        },
+        report::{self, REPORT_SCHEMA_VERSION},
     };
     use crate::sample_circuits::zcash as sample_circuits;
-    use halo2curves::bn256;
-    use zcash_halo2_proofs::pasta::Fp as Fr;
+    use zcash_halo2_proofs::pasta::{Fp as Fr, Fq};
+    use zcash_halo2_proofs::plonk::Expression;
 
-    use num::{BigInt, Num};
+    use clap::Parser;
+    use num::{BigUint, Num};
+    use group::ff::Field;
     use std::collections::HashMap;
     use std::marker::PhantomData;
 
@@ -79,12 +91,6 @@ mod tests {
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -94,7 +100,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
@@ -110,12 +116,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -126,7 +126,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -142,12 +142,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -158,7 +152,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -179,12 +173,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -195,7 +183,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -216,12 +204,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -232,7 +214,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -248,12 +230,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -264,7 +240,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -281,12 +257,6 @@ mod tests {
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
         assert!(analyzer.instace_cells.clone().len().eq(&1));
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -297,7 +267,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -319,12 +289,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 3);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -335,7 +299,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -357,12 +321,6 @@ mod tests {
             specified_instance_cols.insert(var.0.clone(), 1);
         }
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -373,7 +331,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -433,12 +391,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -449,7 +401,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         println!("output_status: {:?}", output_status);
@@ -463,12 +415,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -479,7 +425,7 @@ mod tests {
         };
 
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         println!("output_status: {:?}", output_status);
@@ -493,12 +439,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -508,7 +448,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
@@ -523,12 +463,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -538,7 +472,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -553,12 +487,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -568,7 +496,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -582,12 +510,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -597,7 +519,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -611,12 +533,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -626,7 +542,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -640,12 +556,6 @@ mod tests {
 
         let mut analyzer = Analyzer::new(&circuit, k).unwrap();
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Random,
             verification_input: VerificationInput {
@@ -655,7 +565,7 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -674,12 +584,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -689,7 +593,7 @@ mod tests {
             lookup_method: LookupMethod::Uninterpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocalUninterpretedLookups));
@@ -708,12 +612,6 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
-            .unwrap()
-            .to_string();
-
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
             verification_input: VerificationInput {
@@ -723,7 +621,7 @@ mod tests {
             lookup_method: LookupMethod::Interpreted,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
@@ -742,11 +640,318 @@ mod tests {
         specified_instance_cols.insert("I-0-1".to_owned(), 1);
         specified_instance_cols.insert("I-0-0".to_owned(), 1);
 
-        let modulus = bn256::fr::MODULUS_STR;
-        let without_prefix = modulus.trim_start_matches("0x");
-        let prime = BigInt::from_str_radix(without_prefix, 16)
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+    #[test]
+    fn cli_underconstrained_specific_input_test() {
+        let circuit =
+            sample_circuits::lookup_circuits::multiple_lookups::MyCircuit::<Fr>(PhantomData);
+        let k = 11;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "underconstrained",
+            "--lookup-method",
+            "interpreted",
+            "--verification-method",
+            "specific",
+            "--instance",
+            "I-0-0=1",
+            "--instance",
+            "I-0-1=1",
+            "--instance",
+            "I-0-2=6",
+        ])
+        .unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        let analyzer_input = args
+            .analysis
+            .analyzer_input(&analyzer.instace_cells)
+            .unwrap();
+        assert!(analyzer_input
+            .verification_method
+            .eq(&VerificationMethod::Specific));
+        assert!(analyzer_input.lookup_method.eq(&LookupMethod::Interpreted));
+        assert!(analyzer_input.verification_input.instances_string["I-0-2"].eq(&6));
+
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "underconstrained",
+            "--verification-method",
+            "specific",
+            "--instance",
+            "I-0-0=1",
+        ])
+        .unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        assert!(args
+            .analysis
+            .analyzer_input(&analyzer.instace_cells)
+            .is_err());
+    }
+    #[test]
+    fn registry_sample_circuits_test() {
+        let registry = CircuitRegistry::<Fr>::with_sample_circuits();
+        assert!(registry.contains("two_bit_decomp"));
+        assert!(registry.contains("multiple_lookups"));
+
+        let analyzer = registry.build("two_bit_decomp", 11).unwrap();
+        assert!(analyzer.cs.gates.len().eq(&3));
+        assert!(analyzer.instace_cells.contains_key("I-0-0"));
+
+        assert!(registry.build("no_such_circuit", 11).is_err());
+    }
+
+    #[test]
+    fn registry_register_circuit_test() {
+        let mut registry = CircuitRegistry::<Fr>::new();
+        crate::register_circuit!(
+            registry,
+            "my_lookup",
+            sample_circuits::lookup_circuits::lookup::MyCircuit<Fr>
+        );
+        assert!(registry.names().eq(["my_lookup"]));
+        assert!(registry.build("my_lookup", 11).is_ok());
+    }
+    #[test]
+    fn large_constants_fixed_converted_test() {
+        let circuit =
+            sample_circuits::field_constants::large_constants::LargeConstantsCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let modulus = BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+            16,
+        )
+        .unwrap();
+        // The fixed cell holds -2, i.e. p - 2, which does not fit in 64 bits.
+        assert!(analyzer.fixed_converted[0][0].eq(&(modulus.clone() - 2u32)));
+        assert!(field_to_biguint(&-Fr::ONE).eq(&(modulus - 1u32)));
+    }
+
+    #[test]
+    fn large_constants_not_under_constrained_test() {
+        let circuit =
+            sample_circuits::field_constants::large_constants::LargeConstantsCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn multirow_not_under_constrained_random_input_test() {
+        let circuit = sample_circuits::bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuit::<
+            Fr,
+        >::default();
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn multirow_under_constrained_random_input_test() {
+        // The selector is only enabled on row 0, so the `b0_binary_check` gate must not be
+        // applied to `b1` on row 1.
+        let circuit = sample_circuits::bit_decomposition::two_bit_decomp_multirow::MultiRowTwoBitDecompCircuitUnderConstrained::<
+            Fr,
+        >::default();
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn instance_gate_instance_cells_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The instance cell is only queried by a gate, it is not part of any copy constraint.
+        assert!(analyzer.permutation.is_empty());
+        assert!(analyzer.instace_cells.len().eq(&1));
+        assert!(analyzer.instace_cells.contains_key("I-0-0"));
+    }
+
+    #[test]
+    fn instance_gate_two_rows_instance_cells_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitTwoRows::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The gates are disabled on the second row of the region, so I-0-1 is not a public input of the circuit.
+        assert!(analyzer.instace_cells.len().eq(&1));
+        assert!(analyzer.instace_cells.contains_key("I-0-0"));
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 6);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_specific_input_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        // Both 3 and -3 are square roots of 9.
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn range_decomp_not_under_constrained_interpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuit::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Interpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
-            .to_string();
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
+    }
+
+    #[test]
+    fn range_decomp_not_under_constrained_inline_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuit::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
 
         let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
             verification_method: VerificationMethod::Specific,
@@ -757,9 +962,1278 @@ mod tests {
             lookup_method: LookupMethod::InlineConstraints,
         };
         let output_status = analyzer
-            .analyze_underconstrained(analyzer_input, &prime)
+            .analyze_underconstrained(analyzer_input)
             .unwrap()
             .output_status;
         assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrainedLocal));
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn range_decomp_under_constrained_interpreted_test() {
+        let circuit = sample_circuits::lookup_circuits::range_decomp::RangeDecompCircuitUnderConstrained::<Fr>(PhantomData);
+        let k: u32 = 6;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        assert!(analyzer.instace_cells.len().eq(&1));
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 42);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Interpreted,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        // Without a range check on hi, hi = (42 - lo) / 8 is a valid witness for every lo in 0..8.
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn analyze_unused_custom_gates_findings_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let findings = analyzer.analyze_unused_custom_gates().unwrap().findings;
+        // Only the selector of the "mul" gate is enabled.
+        assert_eq!(
+            findings,
+            vec![Finding::UnusedGate {
+                gate_index: 1,
+                gate_name: "add".to_owned(),
+            }]
+        );
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_findings_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let findings = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .findings;
+        assert_eq!(findings.len(), 1);
+        match &findings[0] {
+            Finding::Underconstrained {
+                model,
+                equivalent_model,
+                differing_cells,
+            } => {
+                assert_eq!(model["I-0-0"], equivalent_model["I-0-0"]);
+                assert_ne!(model["A-0-0"], equivalent_model["A-0-0"]);
+                assert_eq!(
+                    differing_cells,
+                    &vec![CellLocation {
+                        cell: "A-0-0".to_owned(),
+                        region_name: Some("The Region".to_owned()),
+                        column: ColumnLocation {
+                            column_type: ColumnType::Advice,
+                            index: 0,
+                        },
+                        annotation: None,
+                        row: 0,
+                    }]
+                );
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    #[test]
+    fn instance_gate_global_verification_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::Underconstrained {
+                model,
+                equivalent_model,
+                differing_cells,
+            } => {
+                assert_eq!(model["I-0-0"], equivalent_model["I-0-0"]);
+                assert_ne!(model["A-0-0"], equivalent_model["A-0-0"]);
+                assert_eq!(differing_cells.len(), 1);
+                assert_eq!(differing_cells[0].cell, "A-0-0");
+                assert_eq!(differing_cells[0].annotation, None);
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    fn is_zero_input(verification_method: VerificationMethod) -> analyzer_io_type::AnalyzerInput {
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 0);
+        analyzer_io_type::AnalyzerInput {
+            verification_method,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        }
+    }
+
+    #[test]
+    fn is_zero_output_cells_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        // The inverse is free for a zero input.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        match &analyzer_output.findings[0] {
+            Finding::Underconstrained {
+                differing_cells, ..
+            } => {
+                assert_eq!(differing_cells.len(), 1);
+                assert_eq!(differing_cells[0].cell, "A-0-0");
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        // The output is determined.
+        for verification_method in [VerificationMethod::Specific, VerificationMethod::Global] {
+            let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+            analyzer.output_cells = vec![CellSelector::Column(1)];
+            let output_status = analyzer
+                .analyze_underconstrained(is_zero_input(verification_method))
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.output_cells = vec![CellSelector::Region("No Such Region".to_owned())];
+        assert!(analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .is_err());
+    }
+
+    #[test]
+    fn is_zero_hint_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        for verification_method in [VerificationMethod::Specific, VerificationMethod::Global] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .hint(CellSelector::Cell { column: 0, row: 0 })
+                .build()
+                .unwrap();
+            let output_status = analyzer
+                .analyze_underconstrained(is_zero_input(verification_method))
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+    }
+
+    #[test]
+    fn is_zero_assumption_test() {
+        let circuit = sample_circuits::instance_query::is_zero::IsZeroCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+        let non_zero = Assumption::NotEqual {
+            cell: "I-0-0".to_owned(),
+            value: 0,
+        };
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let output_status = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Global))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+        // The inverse is only free for a zero input.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(non_zero.clone())
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Global))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        assert_eq!(analyzer_output.assumptions, vec![non_zero.clone()]);
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Text, &mut report).unwrap();
+        assert!(String::from_utf8(report)
+            .unwrap()
+            .contains("Assuming I-0-0 != 0."));
+
+        // The public input 0 contradicts the assumption.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(non_zero.clone())
+            .build()
+            .unwrap();
+        analyzer.config.unsat_cores = true;
+        let findings = analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .unwrap()
+            .findings;
+        match &findings[0] {
+            Finding::Overconstrained {
+                conflicting_constraints,
+            } => assert!(
+                conflicting_constraints.contains(&ConstraintOrigin::Assumption {
+                    assumption: non_zero,
+                })
+            ),
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .assume(Assumption::Equal {
+                cell: "I-1-0".to_owned(),
+                value: 0,
+            })
+            .build()
+            .unwrap();
+        assert!(analyzer
+            .analyze_underconstrained(is_zero_input(VerificationMethod::Specific))
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_bit_length_assumption_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        // Only one of the square roots a and -a is a small integer.
+        for field_encoding in [
+            FieldEncoding::FiniteField,
+            FieldEncoding::Integer,
+            FieldEncoding::BitVector,
+        ] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .assume("A-0-0 < 2^8".parse().unwrap())
+                .build()
+                .unwrap();
+            analyzer.config.field_encoding = field_encoding;
+            let analyzer_input = global_input(&analyzer.instace_cells);
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input)
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+        }
+    }
+
+    fn global_input(instance_cells: &HashMap<String, i64>) -> analyzer_io_type::AnalyzerInput {
+        analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Global,
+            verification_input: VerificationInput {
+                instances_string: instance_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        }
+    }
+
+    #[test]
+    fn instance_gate_specification_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        // i = 2a and b = a*a.
+        for field_encoding in [
+            FieldEncoding::FiniteField,
+            FieldEncoding::Integer,
+            FieldEncoding::BitVector,
+        ] {
+            let mut analyzer = Analyzer::builder(&circuit, k)
+                .spec("I-0-0 = 2 * A-0-0".parse().unwrap())
+                .spec("4 * A-1-0 = I-0-0 * I-0-0".parse().unwrap())
+                .build()
+                .unwrap();
+            analyzer.config.field_encoding = field_encoding;
+            let analyzer_output = analyzer
+                .analyze_specification(global_input(&analyzer.instace_cells))
+                .unwrap();
+            assert!(analyzer_output
+                .output_status
+                .eq(&AnalyzerOutputStatus::SpecificationHolds));
+            assert!(analyzer_output.findings.is_empty());
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-1-0 = I-0-0".parse().unwrap())
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::SpecificationViolated));
+        match &analyzer_output.findings[..] {
+            [Finding::SpecificationViolation {
+                specification,
+                model,
+            }] => {
+                assert_eq!(specification, "A-1-0 = I-0-0");
+                assert!(model["A-1-0"].ne(&model["I-0-0"]));
+            }
+            findings => panic!("unexpected findings: {:?}", findings),
+        }
+
+        // The polynomial of the "square" gate is zero at the row of the region, and nowhere required to be one.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let square = analyzer.cs.gates[1].polys[0].clone();
+        analyzer.specifications = vec![Specification::Expressions {
+            lhs: square.clone(),
+            rhs: Expression::Constant(Fr::ZERO),
+            row: 0,
+        }];
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationHolds));
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec_expressions(square, Expression::Constant(Fr::ONE), 0)
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+
+        // A-2-0 is not a cell of the circuit, and there must be a specification to check.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-0 = 0".parse().unwrap())
+            .build()
+            .unwrap();
+        assert!(analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .is_err());
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        assert!(analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .is_err());
+    }
+
+    #[test]
+    fn lookup_xor_specification_test() {
+        let circuit = sample_circuits::lookup_circuits::lookup::MyCircuit::<Fr>(PhantomData);
+        let k = 11;
+
+        // The cells of row 1 are looked up in the XOR table of the 3-bit integers less than 6.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = xor(A-0-1, A-1-1, 3)".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationHolds));
+
+        // The operands 4 and 5 do not fit in 2 bits.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = xor(A-0-1, A-1-1, 2)".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .spec("A-2-1 = A-0-1 + A-1-1".parse().unwrap())
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_specification(global_input(&analyzer.instace_cells))
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::SpecificationViolated));
+    }
+
+    #[test]
+    fn unconstrained_cells_hint_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        // Only the cells of "test 2" are unconstrained, no gate is enabled in this region.
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let unconstrained = analyzer
+            .analyze_unconstrained_cells()
+            .unwrap()
+            .findings
+            .len();
+        assert!(unconstrained > 1);
+
+        // Column 1 has a single cell in "test 2".
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .hint(CellSelector::Column(1))
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer.analyze_unconstrained_cells().unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::UnconstrainedCells));
+        assert_eq!(analyzer_output.findings.len(), unconstrained - 1);
+        for finding in &analyzer_output.findings {
+            match finding {
+                Finding::UnconstrainedCell { region_name, .. } => assert_eq!(region_name, "test 2"),
+                finding => panic!("unexpected finding: {:?}", finding),
+            }
+        }
+
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .hint_region("test 2")
+            .build()
+            .unwrap();
+        let output_status = analyzer
+            .analyze_unconstrained_cells()
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NoUnconstrainedCells));
+    }
+
+    #[test]
+    fn unconstrained_output_cells_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        // The cells of "test 2" occur in no constraint, so they can take any value.
+        let mut analyzer = Analyzer::builder(&circuit, k)
+            .output_cell(CellSelector::Region("test 2".to_owned()))
+            .build()
+            .unwrap();
+        let analyzer_output = analyzer
+            .analyze_underconstrained(global_input(&analyzer.instace_cells))
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+        assert_eq!(analyzer_output.findings.len(), 3);
+        for finding in &analyzer_output.findings {
+            match finding {
+                Finding::FreeCell { cell } => {
+                    assert_eq!(cell.region_name.as_deref(), Some("test 2"))
+                }
+                finding => panic!("unexpected finding: {:?}", finding),
+            }
+        }
+    }
+    #[test]
+    fn instance_gate_not_under_constrained_global_verification_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = global_input(&analyzer.instace_cells);
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn unused_gates_json_report_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer.analyze_unused_custom_gates().unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Json, &mut report).unwrap();
+
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        assert_eq!(report["schema_version"], REPORT_SCHEMA_VERSION);
+        assert_eq!(report["status"], "UnusedCustomGates");
+        assert_eq!(report["findings"][0]["kind"], "unused_gate");
+        assert_eq!(report["findings"][0]["gate_name"], "add");
+    }
+
+    #[test]
+    fn unused_gates_sarif_report_test() {
+        let circuit: sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit<Fr> =
+            sample_circuits::bit_decomposition::add_multiplication::AddMultCircuit::default();
+        let k = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_output = analyzer.analyze_unused_custom_gates().unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Sarif, &mut report).unwrap();
+
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        assert_eq!(report["version"], "2.1.0");
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0]["ruleId"], "unused-gate");
+        assert_eq!(
+            results[0]["locations"][0]["logicalLocations"][0]["fullyQualifiedName"],
+            "gate[1]:add"
+        );
+    }
+
+    #[test]
+    fn under_constrained_sarif_fingerprint_test() {
+        let circuit =
+            sample_circuits::bit_decomposition::two_bit_decomp::TwoBitDecompCircuitUnderConstrained::<
+                Fr,
+            >::default();
+        let k: u32 = 11;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        let mut report = vec![];
+        output_result(None, &analyzer_output, OutputFormat::Sarif, &mut report).unwrap();
+
+        // The fingerprint names the columns of the differing cells, not their rows, which depend on the witnesses.
+        let report: serde_json::Value = serde_json::from_slice(&report).unwrap();
+        let results = report["runs"][0]["results"].as_array().unwrap();
+        assert!(!results.is_empty());
+        let fingerprint = results[0]["partialFingerprints"]["korrektLocation/v1"]
+            .as_str()
+            .unwrap();
+        assert!(fingerprint.starts_with("circuit/region:"));
+        assert!(!fingerprint.contains("A-"));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_file_solver_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver_mode = SolverMode::File;
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_integer_encoding_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.field_encoding = FieldEncoding::Integer;
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_bit_vector_encoding_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.field_encoding = FieldEncoding::BitVector;
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+    }
+
+    #[test]
+    fn unsupported_field_encoding_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver = SolverKind::Z3;
+        analyzer.config.field_encoding = FieldEncoding::FiniteField;
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        assert!(analyzer
+            .analyze_underconstrained(analyzer_input)
+            .is_err());
+    }
+
+    #[test]
+    fn smt_artifacts_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let output_dir = std::env::temp_dir().join("korrekt-smt-artifacts-test-zcash");
+        let _ = std::fs::remove_dir_all(&output_dir);
+        for keep_artifacts in [false, true] {
+            let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+            analyzer.config.output_dir = Some(output_dir.clone());
+            analyzer.config.keep_artifacts = keep_artifacts;
+
+            let mut specified_instance_cols = HashMap::new();
+            specified_instance_cols.insert("I-0-0".to_owned(), 9);
+            let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+                verification_method: VerificationMethod::Specific,
+                verification_input: VerificationInput {
+                    instances_string: specified_instance_cols,
+                    iterations: 1,
+                },
+                lookup_method: LookupMethod::InlineConstraints,
+            };
+            let output_status = analyzer
+                .analyze_underconstrained(analyzer_input)
+                .unwrap()
+                .output_status;
+            assert!(output_status.eq(&AnalyzerOutputStatus::Underconstrained));
+
+            let smt_files = std::fs::read_dir(&output_dir).unwrap().count();
+            assert_eq!(smt_files, usize::from(keep_artifacts));
+        }
+        std::fs::remove_dir_all(&output_dir).unwrap();
+    }
+
+    #[test]
+    fn analyze_underconstrained_resource_limit_inconclusive_test() {
+        let circuit: sample_circuits::copy_constraint::fibonacci::FibonacciCircuit<_> =
+            sample_circuits::copy_constraint::fibonacci::FibonacciCircuit::<Fr>(PhantomData);
+        let k: u32 = 11;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.resource_limit = Some(1);
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(matches!(
+            output_status,
+            AnalyzerOutputStatus::Inconclusive { .. }
+        ));
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.solver = SolverKind::Yices;
+        analyzer.config.resource_limit = Some(1);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        assert!(analyzer
+            .analyze_underconstrained(analyzer_input)
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_overconstrained_unsat_core_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+        analyzer.config.unsat_cores = true;
+
+        // 5 generates the multiplicative group of the field, so it is not a square.
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 5);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Overconstrained));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::Overconstrained {
+                conflicting_constraints,
+            } => {
+                assert!(conflicting_constraints.contains(&ConstraintOrigin::Gate {
+                    gate_index: 0,
+                    gate_name: "square".to_owned(),
+                    polynomial_index: 0,
+                    region_name: "The Region".to_owned(),
+                    row: 0,
+                }));
+                assert!(conflicting_constraints.contains(&ConstraintOrigin::PublicInput {
+                    cell: "I-0-0".to_owned(),
+                    value: 5,
+                }));
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+    }
+
+    #[test]
+    fn instance_gate_cell_determinism_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 6);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let cells = analyzer
+            .cell_determinism(&analyzer_input, &CellScope::All)
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            cells.into_iter().collect::<Vec<_>>(),
+            vec![
+                ("A-0-0".to_owned(), Determinism::Determined),
+                ("A-1-0".to_owned(), Determinism::Determined),
+            ]
+        );
+
+        let output_status = analyzer
+            .analyze_cell_determinism(analyzer_input, CellScope::All)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NoFreeCells));
+    }
+
+    #[test]
+    fn cell_determinism_uninterpreted_lookups_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fr>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        // The models of the parallel queries would not be checked against the lookup tables.
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::Uninterpreted,
+        };
+        assert!(analyzer
+            .analyze_cell_determinism(analyzer_input, CellScope::All)
+            .is_err());
+    }
+
+    #[test]
+    fn instance_gate_under_constrained_cell_determinism_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input = |instances_string| analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string,
+                iterations: 2,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer
+            .analyze_cell_determinism(
+                analyzer_input(analyzer.instace_cells.clone()),
+                CellScope::Region("The Region".to_owned()),
+            )
+            .unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::FreeCells));
+        assert_eq!(analyzer_output.findings.len(), 1);
+        match &analyzer_output.findings[0] {
+            Finding::FreeCell { cell } => {
+                assert_eq!(cell.cell, "A-0-0");
+                assert_eq!(cell.region_name.as_deref(), Some("The Region"));
+            }
+            finding => panic!("unexpected finding: {:?}", finding),
+        }
+
+        // Nothing is assigned to the second advice column.
+        let cells = analyzer
+            .cell_determinism(
+                &analyzer_input(analyzer.instace_cells.clone()),
+                &CellScope::Column(1),
+            )
+            .unwrap()
+            .unwrap();
+        assert!(cells.is_empty());
+    }
+
+    #[test]
+    fn counterexample_replay_test() {
+        let circuit = sample_circuits::instance_query::instance_gate::InstanceGateCircuitUnderConstrained::<
+            Fr,
+        >(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let mut specified_instance_cols = HashMap::new();
+        specified_instance_cols.insert("I-0-0".to_owned(), 9);
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Specific,
+            verification_input: VerificationInput {
+                instances_string: specified_instance_cols,
+                iterations: 1,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let analyzer_output = analyzer.analyze_underconstrained(analyzer_input).unwrap();
+        assert!(analyzer_output
+            .output_status
+            .eq(&AnalyzerOutputStatus::Underconstrained));
+
+        let counterexamples = analyzer.counterexamples(&analyzer_output);
+        assert!(counterexamples.len().eq(&1));
+        let counterexample = &counterexamples[0];
+        assert!(counterexample.witness.ne(&counterexample.equivalent_witness));
+        assert!(replay::replay_witness(&circuit, k, &counterexample.witness).is_ok());
+        assert!(replay::replay_witness(&circuit, k, &counterexample.equivalent_witness).is_ok());
+
+        // A witness for another public input does not satisfy the circuit.
+        let mut tampered = counterexample.witness.clone();
+        tampered.insert("I-0-0".to_owned(), "10".to_owned());
+        assert!(replay::replay_witness(&circuit, k, &tampered).is_err());
+
+        let json = report::counterexamples_to_json(&counterexamples).unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+        assert!(value["schema_version"].eq(&REPORT_SCHEMA_VERSION));
+        assert!(value["counterexamples"][0]["witness"]["I-0-0"].eq("9"));
+    }
+
+    #[test]
+    fn cli_cell_determinism_scope_test() {
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "cell-determinism",
+            "--region",
+            "The Region",
+            "--solver",
+            "yices",
+        ])
+        .unwrap();
+        let Some(Command::CellDeterminism(args)) = cli.command else {
+            panic!("expected the cell-determinism subcommand");
+        };
+        assert_eq!(args.scope(), CellScope::Region("The Region".to_owned()));
+        assert!(args.analysis.config().solver.eq(&SolverKind::Yices));
+
+        let cli =
+            Cli::try_parse_from(["korrekt", "cell-determinism", "--column", "1"]).unwrap();
+        let Some(Command::CellDeterminism(args)) = cli.command else {
+            panic!("expected the cell-determinism subcommand");
+        };
+        assert_eq!(args.scope(), CellScope::Column(1));
+
+        assert!(Cli::try_parse_from([
+            "korrekt",
+            "cell-determinism",
+            "--region",
+            "The Region",
+            "--column",
+            "1"
+        ])
+        .is_err());
+    }
+
+    #[test]
+    fn cli_output_cells_test() {
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "underconstrained",
+            "--output-cell",
+            "A-1-0",
+            "--output-cell",
+            "column=1",
+            "--output-cell",
+            "region=Is Zero",
+            "--output-cell",
+            "annotation=out",
+        ])
+        .unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        assert_eq!(
+            args.output_cells,
+            vec![
+                CellSelector::Cell { column: 1, row: 0 },
+                CellSelector::Column(1),
+                CellSelector::Region("Is Zero".to_owned()),
+                CellSelector::Annotation("out".to_owned()),
+            ]
+        );
+
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "unconstrained-cells",
+            "--hint",
+            "annotation=inv",
+            "--hint",
+            "A-0-0",
+        ])
+        .unwrap();
+        assert_eq!(
+            cli.hints,
+            vec![
+                CellSelector::Annotation("inv".to_owned()),
+                CellSelector::Cell { column: 0, row: 0 },
+            ]
+        );
+
+        for selector in ["I-0-0", "A-1", "row=0"] {
+            assert!(Cli::try_parse_from([
+                "korrekt",
+                "underconstrained",
+                "--output-cell",
+                selector
+            ])
+            .is_err());
+        }
+        // Only the under-constrained analysis has output cells and counterexamples.
+        for command in [
+            vec!["korrekt", "cell-determinism"],
+            vec!["korrekt", "specification", "--spec", "A-0-0 = 0"],
+        ] {
+            assert!(Cli::try_parse_from(command.clone()).is_ok());
+            for flag in [
+                ["--output-cell", "A-0-0"],
+                ["--counterexample", "witness.json"],
+            ] {
+                let mut args = command.clone();
+                args.extend(flag);
+                assert!(Cli::try_parse_from(args).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn assumption_syntax_test() {
+        let assumptions = [
+            "I-0-0 = -1",
+            "I-0-0 != 0",
+            "A-1-2 in {0, 1, 5}",
+            "I-0-1 < 2^64",
+        ];
+        for assumption in assumptions {
+            let parsed: Assumption = assumption.parse().unwrap();
+            assert_eq!(parsed.to_string(), assumption);
+        }
+        assert_eq!(
+            "A-1-2 in {0,1}".parse::<Assumption>().unwrap(),
+            Assumption::OneOf {
+                cell: "A-1-2".to_owned(),
+                values: vec![0, 1],
+            }
+        );
+        for assumption in [
+            "I-0-0",
+            "I-0-0 = x",
+            "I-0-0 < 64",
+            "I-0-0 in 1, 2",
+            "I-0-0 in {}",
+        ] {
+            assert!(assumption.parse::<Assumption>().is_err());
+        }
+
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "underconstrained",
+            "--assume",
+            "I-0-0 < 2^64",
+            "--assume",
+            "I-0-0 != 0",
+        ])
+        .unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        assert_eq!(
+            args.analysis.assumptions,
+            vec![
+                Assumption::BitLength {
+                    cell: "I-0-0".to_owned(),
+                    bits: 64,
+                },
+                Assumption::NotEqual {
+                    cell: "I-0-0".to_owned(),
+                    value: 0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn spec_syntax_test() {
+        let specifications = [
+            "I-0-0 = A-0-0 * A-1-0 + A-2-0",
+            "A-2-1 = xor(A-0-1, A-1-1, 8)",
+            "-(A-0-0 + 1) * 3 = A-0-0 - (A-1-0 - 2)",
+            "A-0-0 * (A-1-0 * A-2-0) = -A-0-0 + -2",
+        ];
+        for specification in specifications {
+            let parsed: SpecEquation = specification.parse().unwrap();
+            assert_eq!(parsed.to_string(), specification);
+        }
+        assert_eq!(
+            "I-0-0=A-0-0-1".parse::<SpecEquation>().unwrap(),
+            SpecEquation {
+                lhs: SpecTerm::Cell("I-0-0".to_owned()),
+                rhs: SpecTerm::Sub(
+                    Box::new(SpecTerm::Cell("A-0-0".to_owned())),
+                    Box::new(SpecTerm::Constant(BigUint::from(1u8))),
+                ),
+            }
+        );
+        for specification in [
+            "A-0-0",
+            "A-0-0 =",
+            "A-0 = 1",
+            "A-0-0 = xor(A-0-0, 1)",
+            "A-0-0 = 1 1",
+            "A-0-0 == 1",
+            "A-0-0 = (1",
+        ] {
+            assert!(specification.parse::<SpecEquation>().is_err());
+        }
+
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "specification",
+            "--spec",
+            "I-0-0 = 2 * A-0-0",
+            "--verification-method",
+            "global",
+        ])
+        .unwrap();
+        let Some(Command::Specification(args)) = cli.command else {
+            panic!("expected the specification subcommand");
+        };
+        assert_eq!(
+            args.specifications,
+            vec!["I-0-0 = 2 * A-0-0".parse::<SpecEquation>().unwrap()]
+        );
+        assert!(Cli::try_parse_from(["korrekt", "specification"]).is_err());
+
+        // Random verification is reported as what it is solved as, a check for every input.
+        let analyzer_input = Analyzer::<Fr>::specification_input(analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: HashMap::new(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        });
+        assert!(analyzer_input
+            .verification_method
+            .eq(&VerificationMethod::Global));
+    }
+
+    #[test]
+    fn cli_solver_config_test() {
+        let cli = Cli::try_parse_from([
+            "korrekt",
+            "underconstrained",
+            "--solver",
+            "z3",
+            "--encoding",
+            "integer",
+            "--solver-mode",
+            "file",
+            "--smt-dir",
+            "smt",
+            "--keep-smt",
+            "--timeout",
+            "500",
+            "--resource-limit",
+            "1000",
+            "--unsat-cores",
+        ])
+        .unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        let config = args.analysis.config();
+        assert!(config.solver.eq(&SolverKind::Z3));
+        assert!(config.field_encoding.eq(&FieldEncoding::Integer));
+        assert!(config.solver_mode.eq(&SolverMode::File));
+        assert_eq!(config.output_dir, Some(std::path::PathBuf::from("smt")));
+        assert!(config.keep_artifacts);
+        assert_eq!(config.timeout, Some(std::time::Duration::from_millis(500)));
+        assert_eq!(config.resource_limit, Some(1000));
+        assert!(config.unsat_cores);
+
+        let cli = Cli::try_parse_from(["korrekt", "underconstrained"]).unwrap();
+        let Some(Command::Underconstrained(args)) = cli.command else {
+            panic!("expected the underconstrained subcommand");
+        };
+        let config = args.analysis.config();
+        assert!(config.solver.eq(&SolverKind::Cvc5));
+        assert!(config.timeout.is_none());
+        assert!(!config.keep_artifacts);
+        assert!(!config.unsat_cores);
+    }
+
+    #[test]
+    fn field_modulus_test() {
+        let pasta_fp = BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc094cf91b992d30ed00000001",
+            16,
+        )
+        .unwrap();
+        let pasta_fq = BigUint::from_str_radix(
+            "40000000000000000000000000000000224698fc0994a8dd8c46eb2100000001",
+            16,
+        )
+        .unwrap();
+        assert!(field_modulus::<Fr>().eq(&pasta_fp));
+        assert!(field_modulus::<Fq>().eq(&pasta_fq));
+    }
+
+    #[test]
+    fn instance_gate_not_under_constrained_pasta_fq_test() {
+        let circuit =
+            sample_circuits::instance_query::instance_gate::InstanceGateCircuit::<Fq>(PhantomData);
+        let k: u32 = 5;
+
+        let mut analyzer = Analyzer::new(&circuit, k).unwrap();
+
+        let analyzer_input: analyzer_io_type::AnalyzerInput = analyzer_io_type::AnalyzerInput {
+            verification_method: VerificationMethod::Random,
+            verification_input: VerificationInput {
+                instances_string: analyzer.instace_cells.clone(),
+                iterations: 5,
+            },
+            lookup_method: LookupMethod::InlineConstraints,
+        };
+        let output_status = analyzer
+            .analyze_underconstrained(analyzer_input)
+            .unwrap()
+            .output_status;
+        assert!(output_status.eq(&AnalyzerOutputStatus::NotUnderconstrained));
+    }
+}